@@ -1,41 +1,79 @@
 use crate::display::Analysis;
-use crate::display::Line;
-use crate::distributions::{Continuous, Discrete, Distribution};
+use crate::display::{Line, LineList};
+use crate::distributions::{Continuous, Discrete, Distribution, Normal};
+use crate::math::Round;
+use crate::utils::{err, Result};
 use std::fmt::Display;
 
 pub trait Analyze<T> {
-    fn analyze(&self, values: &Vec<T>) -> Analysis;
+    fn analyze(&self, values: &[T]) -> Analysis;
 }
 
 impl<D: Distribution + Discrete> Analyze<u64> for D {
-    fn analyze(&self, values: &Vec<u64>) -> Analysis {
+    fn analyze(&self, values: &[u64]) -> Analysis {
         Analysis {
             expected: self.mean(),
             variance: self.variance(),
+            moments: vec![],
             pdf_eval: lines(values, |v| self.pmf(v), Format::pmf),
-            cdf_eval: cdf_intervals(values, |v| self.cdf(v)),
+            cdf_eval: cdf_intervals(values, |v| self.cdf(v), |v| self.sf(v)),
             title: self.title(),
         }
     }
 }
 
 impl<D: Distribution + Continuous> Analyze<f64> for D {
-    fn analyze(&self, values: &Vec<f64>) -> Analysis {
+    fn analyze(&self, values: &[f64]) -> Analysis {
         Analysis {
             expected: self.mean(),
             variance: self.variance(),
+            moments: vec![],
             pdf_eval: lines(values, |v| self.pdf(v), Format::pdf),
-            cdf_eval: cdf_intervals(values, |v| self.cdf(v)),
+            cdf_eval: cdf_intervals(values, |v| self.cdf(v), |v| self.sf(v)),
             title: self.title(),
         }
     }
 }
 
+/// The median, mode, skewness, excess kurtosis and entropy, and the
+/// raw and central moments up to the 4th, leaving out any that do not
+/// exist. A value within rounding of 0, on the scale of the standard
+/// deviation to the power it has, is shown as 0, as for the skewness of
+/// a symmetric distribution found by summing.
+pub fn moments<D: Distribution>(dist: &D) -> Vec<Line> {
+    let sd = dist.variance().map(f64::sqrt).filter(|v| v.is_finite());
+    let sd = sd.unwrap_or(1.0);
+    let snap = |v: f64, k: i32| match v.abs() < 1e-12 * sd.powi(k) {
+        true => 0.0,
+        false => v,
+    };
+    let shape = [
+        ("median", dist.median(), 1),
+        ("mode", dist.mode(), 1),
+        ("skewness", dist.skewness(), 0),
+        ("excess kurtosis", dist.excess_kurtosis(), 0),
+        ("entropy (nats)", dist.entropy(), 0),
+    ];
+    let powers = [(2, "²"), (3, "³"), (4, "⁴")];
+    let raw = powers.map(|(k, s)| (format!("E(X{s})"), dist.raw_moment(k), k));
+    // the 2nd central moment is the variance
+    let central = powers[1..]
+        .iter()
+        .map(|(k, s)| (format!("E((X - μ){s})"), dist.central_moment(*k), *k));
+    shape
+        .map(|(desc, v, k)| (desc.to_string(), v, k))
+        .into_iter()
+        .chain(raw)
+        .chain(central)
+        .filter_map(|(desc, v, k)| Some(Line::new(&desc, Some(snap(v?, k)))))
+        .collect()
+}
+
 /// Generally, T is expected to be either u64 or f64
 /// math_fn takes T and maps them to floating-point values
 /// fmt_fn takes T and maps them to descriptions
 pub fn lines<T: Display + Copy, M: Fn(T) -> f64, F: Fn(T) -> String>(
-    list: &Vec<T>,
+    list: &[T],
     math_fn: M,
     fmt_fn: F,
 ) -> Vec<Line> {
@@ -62,11 +100,14 @@ impl Format {
 /// Takes a list of n points supplied by the user
 /// and maps them to n + 1 intervals calculated by c.d.f.
 /// * starts from P(X <= first element)
-/// * ends at P(X > last element)
-pub fn cdf_intervals<T: Display + Copy, F: Fn(T) -> f64>(
-    list: &Vec<T>,
-    cdf: F,
-) -> Vec<Line> {
+/// * ends at P(X > last element), from the survival function sf, as
+///   1 - cdf cancels to 0 far out in the upper tail
+pub fn cdf_intervals<T, F, S>(list: &[T], cdf: F, sf: S) -> Vec<Line>
+where
+    T: Display + Copy,
+    F: Fn(T) -> f64,
+    S: Fn(T) -> f64,
+{
     let mut result = vec![];
 
     // descriptions for first/mid/last ranges
@@ -91,9 +132,49 @@ pub fn cdf_intervals<T: Display + Copy, F: Fn(T) -> f64>(
             // middle element: calculate P(left < X <= right)
             Some(rb) => (mid(lb, rb), cdf(**rb) - cdf(*lb)),
             // last element: calculate P(X > x)
-            None => (last(lb), 1.0 - cdf(*lb)),
+            None => (last(lb), sf(*lb)),
         };
         result.push(Line::new(&desc, Some(val)));
     }
-    return result;
+    result
+}
+
+/// Compares each interval of cdf_intervals() with its approximation by
+/// a normal distribution of the same mean and variance, both as is and
+/// with a continuity correction, which moves each bound x to x + 0.5.
+pub fn normal_approximation<D: Distribution + Discrete>(
+    dist: &D,
+    values: &[u64],
+) -> Result<LineList> {
+    let (Some(m), Some(v)) = (dist.mean(), dist.variance()) else {
+        return err("The distribution has no finite mean or variance.");
+    };
+    if v <= 0.0 {
+        return err("The normal approximation needs a positive variance.");
+    }
+    let normal = Normal::new(m, v.sqrt())?;
+    let exact = cdf_intervals(values, |x| dist.cdf(x), |x| dist.sf(x));
+    let plain = cdf_intervals(
+        values,
+        |x| normal.cdf(x as f64),
+        |x| normal.sf(x as f64),
+    );
+    let corrected = cdf_intervals(
+        values,
+        |x| normal.cdf(x as f64 + 0.5),
+        |x| normal.sf(x as f64 + 0.5),
+    );
+    let mut list = LineList::new();
+    let (m, v) = (m.roundn(10), v.roundn(10));
+    list.header(&format!("normal approximation N({m}, {v})"));
+    for ((line, a), c) in exact.iter().zip(plain).zip(corrected) {
+        list.header(&line.desc);
+        let (e, a, c) = (line.val.unwrap(), a.val.unwrap(), c.val.unwrap());
+        list.push("exact", e);
+        list.push("normal", a);
+        list.push("absolute error", (a - e).abs());
+        list.push("continuity corrected", c);
+        list.push("corrected absolute error", (c - e).abs());
+    }
+    Ok(list)
 }
@@ -0,0 +1,171 @@
+use crate::data_set::Data;
+use crate::display::LineList;
+use crate::distributions::{ChiSquared, Continuous, Distribution, Normal};
+use crate::inverse::Invert;
+use crate::math::Round;
+use crate::utils::{err, Result};
+use statrs::function::beta::inv_beta_reg;
+
+/// "95% CI" for alpha = 0.05
+pub fn label(alpha: f64) -> String {
+    format!("{}% CI", ((1.0 - alpha) * 100.0).roundn(10))
+}
+
+/// two-sided confidence interval of the form
+/// estimate ± critical value × standard error,
+/// using the central 1 - alpha region of `dist`
+pub fn symmetric<D: Distribution + Continuous>(
+    dist: &D,
+    alpha: f64,
+    estimate: f64,
+    std_err: f64,
+) -> LineList {
+    let mut list = LineList::new();
+    let (a, b) = dist.bounds(1.0 - alpha);
+    let label = label(alpha);
+    list.push(&format!("{label} lower bound"), estimate + a * std_err);
+    list.push(&format!("{label} upper bound"), estimate + b * std_err);
+    list
+}
+
+/// checks that x wins out of n trials is a valid count
+pub fn wins(x: u64, n: u64) -> Result<()> {
+    match n == 0 || x > n {
+        true => err("Wins must be between 0 and the number of trials."),
+        false => Ok(()),
+    }
+}
+
+/// sample size, point estimate and standard error of a population
+/// proportion, given x wins out of n trials
+fn estimate(x: u64, n: u64) -> Result<LineList> {
+    wins(x, n)?;
+    let (x, n) = (x as f64, n as f64);
+    let p = x / n;
+    let mut list = LineList::new();
+    list.set_title("CI for p");
+    list.push("sample size", n);
+    list.push("point estimate", p);
+    list.push("standard error", (p * (1.0 - p) / n).sqrt());
+    Ok(list)
+}
+
+/// lines for the lower and upper bounds of an interval
+fn bounds(alpha: f64, (a, b): (f64, f64)) -> LineList {
+    let label = label(alpha);
+    let mut list = LineList::new();
+    list.push(&format!("{label} lower bound"), a);
+    list.push(&format!("{label} upper bound"), b);
+    list
+}
+
+/// confidence interval for a population proportion, given x wins
+/// out of n trials, using the normal approximation (Wald interval)
+pub fn proportion(x: u64, n: u64, alpha: f64) -> Result<LineList> {
+    let mut list = estimate(x, n)?;
+    list.append(&bounds(alpha, wald(x as f64, n as f64, alpha)?));
+    Ok(list)
+}
+
+/// Wald interval for a population proportion, given x wins out of n
+/// trials: p ± z √(p(1 - p)/n)
+fn wald(x: f64, n: f64, alpha: f64) -> Result<(f64, f64)> {
+    let z = Normal::new(0.0, 1.0)?.inv_cdf(1.0 - alpha / 2.0);
+    let p = x / n;
+    let half = z * (p * (1.0 - p) / n).sqrt();
+    Ok((p - half, p + half))
+}
+
+/// Wilson score interval for a population proportion, given x wins
+/// out of n trials
+fn wilson(x: f64, n: f64, alpha: f64) -> Result<(f64, f64)> {
+    let z = Normal::new(0.0, 1.0)?.inv_cdf(1.0 - alpha / 2.0);
+    let (p, z2) = (x / n, z * z);
+    let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let half =
+        z / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+    Ok((center - half, center + half))
+}
+
+/// Clopper-Pearson (exact) interval for a population proportion,
+/// given x wins out of n trials, from quantiles of Beta distributions
+fn clopper_pearson(x: f64, n: f64, alpha: f64) -> (f64, f64) {
+    let lower = match x > 0.0 {
+        true => inv_beta_reg(x, n - x + 1.0, alpha / 2.0),
+        false => 0.0,
+    };
+    let upper = match x < n {
+        true => inv_beta_reg(x + 1.0, n - x, 1.0 - alpha / 2.0),
+        false => 1.0,
+    };
+    (lower, upper)
+}
+
+/// Wald, Wilson and Clopper-Pearson confidence intervals for a
+/// population proportion, given x wins out of n trials
+pub fn proportion_intervals(x: u64, n: u64, alpha: f64) -> Result<LineList> {
+    let mut list = estimate(x, n)?;
+    let (x, n) = (x as f64, n as f64);
+    let intervals = [
+        ("Wald", wald(x, n, alpha)?),
+        ("Wilson", wilson(x, n, alpha)?),
+        ("Clopper-Pearson", clopper_pearson(x, n, alpha)),
+    ];
+    for (name, interval) in intervals {
+        list.header(name);
+        list.append(&bounds(alpha, interval));
+    }
+    Ok(list)
+}
+
+/// confidence interval for a population variance (and standard
+/// deviation) of a normal population, using χ²(n - 1)
+pub fn variance(d: &mut Data, alpha: f64) -> Result<LineList> {
+    let n = d.values()?.len() as f64;
+    if n < 2.0 {
+        return err("Need at least 2 data points to estimate variance.");
+    }
+    // Data takes a variance that is only rounding error to be 0
+    let var = d.var_s()?;
+    if var == 0.0 {
+        return err("The data has no variance.");
+    }
+    let (a, b) = ChiSquared::new(n as u64 - 1)?.bounds(1.0 - alpha);
+    let (lower, upper) = ((n - 1.0) * var / b, (n - 1.0) * var / a);
+    let label = label(alpha);
+    let mut list = LineList::new();
+    list.set_title("CI for σ²");
+    list.push("sample size", n);
+    list.push("sample variance", var);
+    list.push("degrees of freedom", n - 1.0);
+    list.push(&format!("{label} lower bound"), lower);
+    list.push(&format!("{label} upper bound"), upper);
+    list.header(&format!("{label} for σ"));
+    list.push(&format!("{label} lower bound"), lower.sqrt());
+    list.push(&format!("{label} upper bound"), upper.sqrt());
+    Ok(list)
+}
+
+#[test]
+fn test() -> Result<()> {
+    let bounds = |list: LineList| -> Vec<f64> {
+        list.list.iter().rev().filter_map(|v| v.val).take(2).collect()
+    };
+
+    let ci = bounds(proportion(40, 100, 0.05)?);
+    float_eq!(ci[1], 0.3039817664, 8);
+    float_eq!(ci[0], 0.4960182336, 8);
+
+    let (a, b) = wilson(40.0, 100.0, 0.05)?;
+    float_eq!(a, 0.3094012864, 8);
+    float_eq!(b, 0.4979974132, 8);
+    let (a, b) = clopper_pearson(40.0, 100.0, 0.05);
+    float_eq!(a, 0.3032947687, 8);
+    float_eq!(b, 0.5027908496, 8);
+    assert_eq!(clopper_pearson(0.0, 10.0, 0.05).0, 0.0);
+    assert_eq!(clopper_pearson(10.0, 10.0, 0.05).1, 1.0);
+
+    assert!(proportion(4, 3, 0.05).is_err());
+    assert!(proportion(0, 0, 0.05).is_err());
+    Ok(())
+}
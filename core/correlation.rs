@@ -0,0 +1,148 @@
+use crate::confidence::label;
+use crate::display::LineList;
+use crate::distributions::{Normal, StudentsT};
+use crate::hypothesis::p_value;
+use crate::inverse::Invert;
+use crate::math::ranks;
+use crate::utils::{err, Result};
+use crate::Area;
+
+/// Pearson's correlation coefficient of paired values
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(y) {
+        let (dx, dy) = (x - mx, y - my);
+        (sxx, syy, sxy) = (sxx + dx * dx, syy + dy * dy, sxy + dx * dy);
+    }
+    sxy / (sxx * syy).sqrt()
+}
+
+/// Σ f(t) over the sizes t of each group of tied values
+fn ties(values: &[f64], f: fn(f64) -> f64) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|v| v.len() as f64)
+        .filter(|t| *t > 1.0)
+        .map(f)
+        .sum()
+}
+
+/// Kendall's tau-b, and the z statistic of the number of concordant
+/// minus discordant pairs, with its variance corrected for ties
+fn kendall(x: &[f64], y: &[f64]) -> (f64, f64) {
+    let n = x.len();
+    let (mut s, mut tied_x, mut tied_y) = (0.0, 0.0, 0.0);
+    for i in 0..n {
+        for j in i + 1..n {
+            let sign = (x[i] - x[j]).signum() * (y[i] - y[j]).signum();
+            match (x[i] == x[j], y[i] == y[j]) {
+                (true, true) => (tied_x, tied_y) = (tied_x + 1.0, tied_y + 1.0),
+                (true, false) => tied_x += 1.0,
+                (false, true) => tied_y += 1.0,
+                (false, false) => s += sign,
+            }
+        }
+    }
+    let n = n as f64;
+    let pairs = n * (n - 1.0) / 2.0;
+    let tau = s / ((pairs - tied_x) * (pairs - tied_y)).sqrt();
+
+    let v = |t: f64| t * (t - 1.0) * (2.0 * t + 5.0);
+    let (v1x, v1y) = (ties(x, |t| t * (t - 1.0)), ties(y, |t| t * (t - 1.0)));
+    let v2 = |t: f64| t * (t - 1.0) * (t - 2.0);
+    let (v2x, v2y) = (ties(x, v2), ties(y, v2));
+    let var = (v(n) - ties(x, v) - ties(y, v)) / 18.0
+        + v1x * v1y / (2.0 * n * (n - 1.0))
+        + v2x * v2y / (9.0 * n * (n - 1.0) * (n - 2.0));
+    (tau, s / var.sqrt())
+}
+
+/// t statistic of a correlation coefficient r under H0: ρ = 0
+fn t_statistic(r: f64, n: f64) -> f64 {
+    r * ((n - 2.0) / (1.0 - r * r)).sqrt()
+}
+
+/// t statistic and two-tailed p-value of r, with the degrees of
+/// freedom between them if `freedom`. When r = ±1 the points lie on a
+/// line, so t is infinite and it is left out with its p-value.
+fn t_test(dist: &StudentsT, r: f64, n: f64, freedom: bool) -> LineList {
+    let mut list = LineList::new();
+    let t = t_statistic(r, n);
+    if t.is_finite() {
+        list.push("t statistic", t);
+    }
+    if freedom {
+        list.push("degrees of freedom", n - 2.0);
+    }
+    if t.is_finite() {
+        list.append(&p_value(dist, t, Area::Mid));
+    }
+    list
+}
+
+/// Pearson, Spearman and Kendall correlation of paired values,
+/// each tested against H0: no correlation
+pub fn correlation(points: &[(f64, f64)], alpha: f64) -> Result<LineList> {
+    let x: Vec<_> = points.iter().map(|v| v.0).collect();
+    let y: Vec<_> = points.iter().map(|v| v.1).collect();
+    let n = points.len() as f64;
+    if n < 4.0 {
+        return err("at least 4 pairs are needed.");
+    }
+    let constant = |v: &[f64]| v.iter().all(|a| *a == v[0]);
+    if constant(&x) || constant(&y) {
+        return err("values in each column must not all be equal.");
+    }
+    let t = StudentsT::with_freedom(n - 2.0)?;
+    let z = Normal::new(0.0, 1.0)?;
+    let mut list = LineList::new();
+    list.push("sample size", n);
+
+    let r = pearson(&x, &y);
+    list.header("Pearson");
+    list.push("r", r);
+    list.append(&t_test(&t, r, n, true));
+    // Fisher transformation: atanh(r) is approximately normal
+    let (a, b) = z.bounds(1.0 - alpha);
+    let se = 1.0 / (n - 3.0).sqrt();
+    let label = label(alpha);
+    list.push(&format!("{label} lower bound"), (r.atanh() + a * se).tanh());
+    list.push(&format!("{label} upper bound"), (r.atanh() + b * se).tanh());
+
+    let rho = pearson(&ranks(&x), &ranks(&y));
+    list.header("Spearman");
+    list.push("ρ", rho);
+    list.append(&t_test(&t, rho, n, false));
+
+    let (tau, stat) = kendall(&x, &y);
+    list.header("Kendall");
+    list.push("τ", tau);
+    list.push("z statistic", stat);
+    list.append(&p_value(&z, stat, Area::Mid));
+    Ok(list)
+}
+
+#[test]
+fn test() -> Result<()> {
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = [2.0, 1.0, 4.0, 3.0, 5.0];
+    float_eq!(pearson(&x, &y), 0.8);
+    let (tau, z) = kendall(&x, &y);
+    float_eq!(tau, 0.6);
+    float_eq!(z, 1.4696938457);
+    let (tau, _) = kendall(&[1.0, 1.0, 2.0, 3.0], &[1.0, 2.0, 2.0, 3.0]);
+    float_eq!(tau, 0.8);
+    assert!(correlation(
+        &[(1.0, 2.0), (1.0, 3.0), (1.0, 4.0), (1.0, 5.0)],
+        0.05
+    )
+    .is_err());
+    let line = [(1.0, 2.0), (2.0, 4.0), (3.0, 6.0), (4.0, 8.0)];
+    let list = correlation(&line, 0.05)?;
+    assert!(list.list.iter().all(|v| v.desc != "t statistic"));
+    Ok(())
+}
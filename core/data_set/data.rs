@@ -1,5 +1,7 @@
 use crate::data_set::point::{DataPoint, ParseData};
 use crate::display::LineList;
+use crate::math::{self, Round};
+use crate::plot::{self, Scale};
 use crate::utils::{err, Result};
 
 #[derive(Debug)]
@@ -8,6 +10,7 @@ pub struct Data {
     mean: Option<f64>,
     var_p: Option<f64>,
     var_s: Option<f64>,
+    weighted: bool,
 }
 
 pub enum Parser {
@@ -15,12 +18,32 @@ pub enum Parser {
     PairDiff,
 }
 
-fn parse<A, B, F: FnMut(&A) -> Option<B>>(raw: &Vec<A>, parser: F) -> Vec<B> {
+/// how the bins of a histogram are chosen
+pub enum Bins {
+    Count(u64),
+    Width(f64),
+    /// ⌈log₂ n⌉ + 1 bins
+    Sturges,
+    /// width 3.49 s / ∛n
+    Scott,
+    /// width 2 IQR / ∛n
+    FreedmanDiaconis,
+}
+
+/// most bins a histogram may have
+const MAX_BINS: f64 = 1000.0;
+
+/// a value as it appears in a description
+fn label(v: f64) -> String {
+    v.roundn(10).to_string()
+}
+
+fn parse<A, B, F: FnMut(&A) -> Option<B>>(raw: &[A], parser: F) -> Vec<B> {
     raw.iter().filter_map(parser).collect()
 }
 
 impl Data {
-    pub fn new(raw: &Vec<String>, parser: Parser) -> Result<Self> {
+    pub fn new(raw: &[String], parser: Parser) -> Result<Self> {
         let data = match parser {
             Parser::Single => {
                 let b = parse(raw, |v| v.val_prob());
@@ -31,12 +54,13 @@ impl Data {
             }
             Parser::PairDiff => parse(raw, |v| v.diff()),
         };
-        let mut data = Data { mean: None, var_p: None, var_s: None, data };
+        let mut data =
+            Data { mean: None, var_p: None, var_s: None, data, weighted: true };
         data.balance();
         data.mean().ok();
         data.var_p().ok();
         data.var_s().ok();
-        data.is_valid()
+        data.validate()
     }
 
     /// evenly distributes probability such that sum is 1
@@ -47,6 +71,7 @@ impl Data {
             return;
         }
         let prob = 1.0 / self.n();
+        self.weighted = false;
         self.data =
             self.data.iter().map(|v| DataPoint { val: v.val, prob }).collect();
     }
@@ -55,6 +80,15 @@ impl Data {
         self.data.len() as f64
     }
 
+    /// the values of a sample, one per line. Weighted data describes a
+    /// distribution rather than a sample, so it is rejected.
+    pub fn values(&self) -> Result<Vec<f64>> {
+        match self.weighted {
+            true => err("samples need one number per line, not probabilities."),
+            false => Ok(self.data.iter().map(|v| v.val).collect()),
+        }
+    }
+
     /// Calculate mean and update self.
     pub fn mean(&mut self) -> Result<f64> {
         if let Some(v) = self.mean {
@@ -66,14 +100,22 @@ impl Data {
     }
 
     /// Assumes self.data contains entire population
-    /// and calculates population variance.
+    /// and calculates population variance, summed around the mean so
+    /// that it cannot come out negative. Any variance left only by
+    /// rounding the mean, as for equal values, is taken to be 0.
     pub fn var_p(&mut self) -> Result<f64> {
         if let Some(v) = self.var_p {
             return Ok(v);
         }
         let mean = self.mean()?;
-        let ex2 = self.data.iter().fold(0.0, |a, v| a + v.prob * v.val * v.val);
-        let var_p = ex2 - mean * mean;
+        let var_p = self
+            .data
+            .iter()
+            .fold(0.0, |a, v| a + v.prob * (v.val - mean).powi(2));
+        let var_p = match math::no_variance(var_p, var_p + mean * mean) {
+            true => 0.0,
+            false => var_p,
+        };
         self.var_p = Some(var_p);
         Ok(var_p)
     }
@@ -87,8 +129,232 @@ impl Data {
         self.var_s.ok_or("Unable to get sample variance".to_string())
     }
 
+    /// distinct values with non-zero probability in ascending order,
+    /// each with its total probability
+    pub fn distribution(&self) -> Vec<(f64, f64)> {
+        let mut points: Vec<_> = self
+            .data
+            .iter()
+            .filter(|v| v.prob > 0.0)
+            .map(|v| (v.val, v.prob))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut dist: Vec<(f64, f64)> = vec![];
+        for (val, prob) in points {
+            match dist.last_mut() {
+                Some(last) if last.0 == val => last.1 += prob,
+                _ => dist.push((val, prob)),
+            }
+        }
+        dist
+    }
+
+    /// The q-quantile: the smallest value whose cumulative probability
+    /// reaches q. When q is reached exactly, this is averaged with the
+    /// next value, so that the median of an even-sized sample is the
+    /// mean of the middle two.
+    pub fn quantile(&self, q: f64) -> f64 {
+        let dist = self.distribution();
+        let mut total = 0.0;
+        for (i, (val, prob)) in dist.iter().enumerate() {
+            total += prob;
+            if (total - q).abs() < 1e-10 {
+                return dist.get(i + 1).map_or(*val, |v| (val + v.0) / 2.0);
+            }
+            if total > q {
+                return *val;
+            }
+        }
+        dist.last().map_or(f64::NAN, |v| v.0)
+    }
+
+    /// the most probable values, unless every value is equally probable
+    pub fn modes(&self) -> Vec<f64> {
+        let dist = self.distribution();
+        let max = dist.iter().map(|v| v.1).fold(0.0, f64::max);
+        let modes: Vec<_> = dist
+            .iter()
+            .filter(|v| (v.1 - max).abs() < 1e-10)
+            .map(|v| v.0)
+            .collect();
+        match modes.len() < dist.len() {
+            true => modes,
+            false => vec![],
+        }
+    }
+
+    /// k-th central moment
+    fn moment(&self, k: i32) -> Option<f64> {
+        let mean = self.mean?;
+        Some(self.data.iter().map(|v| v.prob * (v.val - mean).powi(k)).sum())
+    }
+
+    /// skewness and excess kurtosis, if the data is not constant
+    fn shape(&self) -> Option<(f64, f64)> {
+        let m2 = self.moment(2).filter(|v| *v > 1e-10)?;
+        let (m3, m4) = (self.moment(3)?, self.moment(4)?);
+        Some((m3 / m2.powf(1.5), m4 / (m2 * m2) - 3.0))
+    }
+
+    /// five-number summary, Tukey fences and outliers, with a box plot
+    /// whose whiskers reach the most extreme values within the fences
+    pub fn boxplot(&self) -> LineList {
+        let mut list = LineList::new();
+        let (q1, q2, q3) =
+            (self.quantile(0.25), self.quantile(0.5), self.quantile(0.75));
+        let (min, max) = (self.quantile(0.0), self.quantile(1.0));
+        let iqr = q3 - q1;
+        let fences = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let values: Vec<_> = self.distribution().iter().map(|v| v.0).collect();
+        let inside = |v: &&f64| fences.0 <= **v && **v <= fences.1;
+        let outliers: Vec<_> =
+            values.iter().filter(|v| !inside(v)).cloned().collect();
+        let mut within = values.iter().filter(inside);
+        let lo = within.next().map_or(q1, |v| v.min(q1));
+        let hi = within.next_back().map_or(q3, |v| v.max(q3)).max(lo);
+
+        list.push("minimum", min);
+        list.push("1st quartile", q1);
+        list.push("median", q2);
+        list.push("3rd quartile", q3);
+        list.push("maximum", max);
+        list.push("interquartile range", iqr);
+        list.push("lower fence", fences.0);
+        list.push("upper fence", fences.1);
+        outliers.iter().for_each(|v| list.push("outlier", *v));
+        let scale = Scale::new(min, max, plot::WIDTH);
+        let plot = plot::boxplot(&scale, (lo, hi), (q1, q2, q3), &outliers);
+        list.text("box plot", &plot);
+        list
+    }
+
+    /// Bin width chosen by the given rule, falling back to Sturges' rule
+    /// when the data is too concentrated for the rule to apply. Widths
+    /// from rules are rounded to 2 significant figures.
+    fn bin_width(&self, bins: Bins, range: f64) -> f64 {
+        let n = self.n();
+        let sd = match self.weighted {
+            true => self.var_p,
+            false => self.var_s,
+        }
+        .map(f64::sqrt);
+        let width = match bins {
+            Bins::Count(k) => range / k as f64,
+            Bins::Width(w) => w,
+            Bins::Sturges => range / (n.log2().ceil() + 1.0),
+            Bins::Scott => 3.49 * sd.unwrap_or(0.0) / n.cbrt(),
+            Bins::FreedmanDiaconis => {
+                let iqr = self.quantile(0.75) - self.quantile(0.25);
+                2.0 * iqr / n.cbrt()
+            }
+        };
+        let width = match width > 0.0 && width.is_finite() {
+            true => width,
+            false => range / (n.log2().ceil() + 1.0),
+        };
+        match bins {
+            Bins::Count(_) | Bins::Width(_) => width,
+            _ => {
+                let unit = 10f64.powi(width.log10().floor() as i32 - 1);
+                (width / unit).round() * unit
+            }
+        }
+    }
+
+    /// Frequencies of values in equal-width bins starting from the
+    /// minimum, with a bar for each bin. Each bin includes its left
+    /// edge, and the last bin also includes the maximum.
+    pub fn histogram(&self, bins: Bins) -> Result<LineList> {
+        if let Bins::Width(w) = bins {
+            if w <= 0.0 {
+                return err("bin width must be positive.");
+            }
+        }
+        let dist = self.distribution();
+        let (min, max) = (self.quantile(0.0), self.quantile(1.0));
+        let range = max - min;
+        let width = match range > 0.0 {
+            true => self.bin_width(bins, range),
+            false => 1.0,
+        };
+        // allow for rounding errors when the width divides the range
+        let count = (range / width - 1e-9).ceil().max(1.0);
+        if count > MAX_BINS {
+            return err("too many bins.");
+        }
+        let count = count as usize;
+        let mut freq = vec![0.0; count];
+        for (val, prob) in dist {
+            let i = ((val - min) / width).floor() as usize;
+            freq[i.min(count - 1)] += prob;
+        }
+        // unweighted data is shown as counts
+        if !self.weighted {
+            freq.iter_mut().for_each(|v| *v = (*v * self.n()).round());
+        }
+
+        let mut list = LineList::new();
+        list.push("bins", count as f64);
+        list.push("bin width", width);
+        let most = freq.iter().cloned().fold(0.0, f64::max);
+        for (i, f) in freq.iter().enumerate() {
+            let (a, b) = (min + i as f64 * width, min + (i + 1) as f64 * width);
+            let desc = match i + 1 == count {
+                true => format!("[{}, {}]", label(a), label(b.min(max))),
+                false => format!("[{}, {})", label(a), label(b)),
+            };
+            let bar = plot::bar(f / most, plot::WIDTH);
+            list.text(&desc, format!("{} {}", bar, label(*f)).trim_start());
+        }
+        Ok(list)
+    }
+
+    /// Stem-and-leaf display. Values are rounded to the leaf unit, a
+    /// power of 10 chosen so that there are at most 10 leaves per stem
+    /// and at least 2 stems when the values differ.
+    pub fn stem(&self) -> Result<LineList> {
+        if self.weighted {
+            return err("stem-and-leaf displays need one number per line.");
+        }
+        let (min, max) = (self.quantile(0.0), self.quantile(1.0));
+        let magnitude = match max - min {
+            r if r > 0.0 => r,
+            _ => min.abs().max(1.0),
+        };
+        let unit = 10f64.powi(magnitude.log10().floor() as i32 - 1);
+        let mut ints: Vec<_> =
+            self.data.iter().map(|v| (v.val / unit).round() as i64).collect();
+        ints.sort();
+        // (negative, stem, leaf), ordered from smallest to largest
+        let keys: Vec<_> = ints
+            .iter()
+            .map(|v| (*v < 0, v.unsigned_abs() / 10, v.unsigned_abs() % 10))
+            .collect();
+        let stems = |neg: bool| keys.iter().filter(move |k| k.0 == neg);
+        let (lo, hi) = (keys[0], keys[keys.len() - 1]);
+
+        let mut list = LineList::new();
+        list.push("leaf unit", unit);
+        let mut row = |neg: bool, s: u64| {
+            let leaves: String = stems(neg)
+                .filter(|k| k.1 == s)
+                .map(|k| char::from_digit(k.2 as u32, 10).unwrap_or('?'))
+                .collect();
+            let sign = if neg { "-" } else { "" };
+            list.text(&format!("{}{}", sign, s), &leaves);
+        };
+        if lo.0 {
+            (0..=lo.1).rev().for_each(|s| row(true, s));
+        }
+        if !hi.0 {
+            let first = if lo.0 { 0 } else { lo.1 };
+            (first..=hi.1).for_each(|s| row(false, s));
+        }
+        Ok(list)
+    }
+
     /// true if and only if dataset is non-empty and total probability adds up to 1
-    pub fn is_valid(self) -> Result<Self> {
+    pub fn validate(self) -> Result<Self> {
         if self.data.is_empty() {
             return err("empty dataset.");
         }
@@ -101,15 +367,70 @@ impl Data {
 
     pub fn export(&self) -> LineList {
         let mut list = LineList::new();
-        let mut push = |d: &str, v: &Option<f64>| match v {
-            Some(v) => list.push(d, *v),
-            None => (),
+        let mut push = |d: &str, v: &Option<f64>| {
+            if let Some(v) = v {
+                list.push(d, *v)
+            }
         };
         push("mean", &self.mean);
         push("population variance", &self.var_p);
         push("population std.dev", &self.var_p.map(|v| v.sqrt()));
-        push("sample variance", &self.var_s);
-        push("sample std.err", &self.var_s.map(|v| v.sqrt()));
+        // weighted data describes a distribution rather than a sample
+        if !self.weighted {
+            push("sample variance", &self.var_s);
+            push("sample std.dev", &self.var_s.map(|v| v.sqrt()));
+        }
+
+        let (sd, n) = match self.weighted {
+            true => (self.var_p.map(|v| v.sqrt()), None),
+            false => (self.var_s.map(|v| v.sqrt()), Some(self.n())),
+        };
+        let se = sd.zip(n).map(|(sd, n)| sd / n.sqrt());
+        push("std.err of mean", &se.filter(|v| v.is_finite()));
+        let cv = sd.zip(self.mean).map(|(sd, m)| sd / m.abs());
+        push("coeff. of variation", &cv.filter(|v| v.is_finite()));
+
+        let (min, max) = (self.quantile(0.0), self.quantile(1.0));
+        let (q1, q3) = (self.quantile(0.25), self.quantile(0.75));
+        push("minimum", &Some(min));
+        push("1st quartile", &Some(q1));
+        push("median", &Some(self.quantile(0.5)));
+        push("3rd quartile", &Some(q3));
+        push("maximum", &Some(max));
+        push("range", &Some(max - min));
+        push("interquartile range", &Some(q3 - q1));
+        self.modes().iter().for_each(|v| push("mode", &Some(*v)));
+        if let Some((skew, kurt)) = self.shape() {
+            push("skewness", &Some(skew));
+            push("excess kurtosis", &Some(kurt));
+        }
         list
     }
 }
+
+#[test]
+fn test() -> Result<()> {
+    let data = |v: &[&str]| {
+        let raw = v.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        Data::new(&raw, Parser::Single)
+    };
+    let d = data(&["3", "1", "2", "2"])?;
+    float_eq!(d.quantile(0.25), 1.5);
+    float_eq!(d.quantile(0.5), 2.0);
+    float_eq!(d.quantile(0.6), 2.0);
+    float_eq!(d.quantile(1.0), 3.0);
+    assert_eq!(d.modes(), vec![2.0]);
+    assert!(data(&["1", "2"])?.modes().is_empty());
+
+    let d = data(&["10 0.1", "20 0.2", "30 0.4", "50 0.3"])?;
+    float_eq!(d.quantile(0.3), 25.0);
+    float_eq!(d.quantile(0.5), 30.0);
+    assert_eq!(d.modes(), vec![30.0]);
+    let (skew, kurt) = data(&["1", "2", "3"])?.shape().unwrap();
+    float_eq!(skew, 0.0);
+    float_eq!(kurt, -1.5);
+    // the mean of these is not exactly 3.3
+    let mut d = data(&["3.3"; 6])?;
+    assert_eq!(d.var_p()?, 0.0);
+    Ok(())
+}
@@ -5,17 +5,71 @@ use std::env;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-pub use data::{Data, Parser};
+pub use data::{Bins, Data, Parser};
+use point::ParseData;
 
 fn open_file(file: &str) -> Result<File> {
     let cwd = env::current_dir().serr("Unable to get current dir.")?;
     File::open(cwd.join(file)).serr("Unable to get file")
 }
 
+fn read_lines(file: &str) -> Result<Vec<String>> {
+    match open_file(file) {
+        Err(_) => err(&format!("Unable to open file {}", file)),
+        Ok(v) => Ok(BufReader::new(v).lines().map_while(|v| v.ok()).collect()),
+    }
+}
+
 pub fn analyze(file: &str, parser: Parser) -> Result<Data> {
-    let lines = match open_file(file) {
-        Err(_) => return err(&format!("Unable to open file {}", file)),
-        Ok(v) => BufReader::new(v).lines().filter_map(|v| v.ok()).collect(),
-    };
-    Data::new(&lines, parser)
+    Data::new(&read_lines(file)?, parser)
+}
+
+/// reads a file of number pairs, skipping lines that cannot be parsed
+pub fn pairs(file: &str) -> Result<Vec<(f64, f64)>> {
+    let pairs: Vec<_> =
+        read_lines(file)?.iter().filter_map(|v| v.pair()).collect();
+    match pairs.is_empty() {
+        true => err("empty dataset."),
+        false => Ok(pairs),
+    }
+}
+
+/// reads a file of numbers, one per line, as a single sample, or the
+/// differences x - y of a file of <x> <y> pairs
+pub fn sample(file: &str, parser: Parser) -> Result<Vec<f64>> {
+    analyze(file, parser)?.values()
+}
+
+/// reads a file of <group> <value> lines into samples, in the order
+/// that each group first appears
+pub fn groups(file: &str) -> Result<Vec<(String, Vec<f64>)>> {
+    let mut groups: Vec<(String, Vec<f64>)> = vec![];
+    for line in read_lines(file)? {
+        let Some((name, v)) = line.trim().split_once(char::is_whitespace)
+        else {
+            continue;
+        };
+        let Some(v) = v.to_string().row().filter(|v| v.len() == 1) else {
+            continue;
+        };
+        match groups.iter_mut().find(|g| g.0 == name) {
+            Some(g) => g.1.push(v[0]),
+            None => groups.push((name.to_string(), v)),
+        }
+    }
+    match groups.is_empty() {
+        true => err("empty dataset."),
+        false => Ok(groups),
+    }
+}
+
+/// reads a file of whitespace-separated numbers into rows,
+/// skipping lines that cannot be parsed
+pub fn table(file: &str) -> Result<Vec<Vec<f64>>> {
+    let rows: Vec<_> =
+        read_lines(file)?.iter().filter_map(|v| v.row()).collect();
+    match rows.is_empty() {
+        true => err("empty dataset."),
+        false => Ok(rows),
+    }
 }
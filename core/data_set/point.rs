@@ -14,8 +14,10 @@ impl fmt::Display for DataPoint {
 
 pub trait ParseData {
     fn diff(&self) -> Option<DataPoint>;
+    fn pair(&self) -> Option<(f64, f64)>;
     fn point(&self) -> Option<DataPoint>;
     fn val_prob(&self) -> Option<DataPoint>;
+    fn row(&self) -> Option<Vec<f64>>;
 }
 
 fn parse(v: &str) -> Option<f64> {
@@ -24,8 +26,13 @@ fn parse(v: &str) -> Option<f64> {
 
 impl ParseData for String {
     fn diff(&self) -> Option<DataPoint> {
+        let (a, b) = self.pair()?;
+        Some(DataPoint { val: a - b, prob: 0.0 })
+    }
+
+    fn pair(&self) -> Option<(f64, f64)> {
         let p = self.split_once(' ').unwrap_or_default();
-        Some(DataPoint { val: parse(p.0)? - parse(p.1)?, prob: 0.0 })
+        Some((parse(p.0)?, parse(p.1)?))
     }
 
     fn val_prob(&self) -> Option<DataPoint> {
@@ -36,4 +43,11 @@ impl ParseData for String {
     fn point(&self) -> Option<DataPoint> {
         Some(DataPoint { prob: 0.0, val: parse(self)? })
     }
+
+    /// whitespace-separated numbers, such as a row of a table
+    fn row(&self) -> Option<Vec<f64>> {
+        let row =
+            self.split_whitespace().map(parse).collect::<Option<Vec<_>>>();
+        row.filter(|v| !v.is_empty())
+    }
 }
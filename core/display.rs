@@ -1,16 +1,24 @@
-use crate::math::Round;
-use crate::printer::Printer;
+use crate::math::Precision;
+use crate::printer::{self, Printer};
+use crate::Format;
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug)]
+/// A described value, or a described piece of text such as a plot.
+/// Lines with neither are section headers.
+#[derive(Debug, Clone)]
 pub struct Line {
     pub desc: String,
     pub val: Option<f64>,
+    pub text: Option<String>,
 }
 
 impl Line {
     pub fn new(desc: &str, val: Option<f64>) -> Self {
-        Self { desc: desc.to_string(), val: val.map(|v| v.roundn(10)) }
+        Self { desc: desc.to_string(), val, text: None }
+    }
+    pub fn text(desc: &str, text: &str) -> Self {
+        let text = Some(text.to_string());
+        Self { desc: desc.to_string(), val: None, text }
     }
 }
 
@@ -19,6 +27,8 @@ pub struct Analysis {
     pub title: String,
     pub expected: Option<f64>,
     pub variance: Option<f64>,
+    /// median, mode, shape and higher moments, if asked for
+    pub moments: Vec<Line>,
     pub pdf_eval: Vec<Line>,
     pub cdf_eval: Vec<Line>,
 }
@@ -32,47 +42,69 @@ impl LineList {
     pub fn push(&mut self, desc: &str, val: f64) {
         self.list.push(Line::new(desc, Some(val)));
     }
+    pub fn text(&mut self, desc: &str, text: &str) {
+        self.list.push(Line::text(desc, text));
+    }
     pub fn header(&mut self, header: &str) {
-        self.list.push(Line::new(&format!("[{}]", header), None));
+        self.list.push(Line::new(header, None));
     }
     pub fn new() -> Self {
         Self { list: Vec::new(), title: String::new() }
     }
+    /// a single value without a description
+    pub fn value(val: f64) -> Self {
+        let mut list = LineList::new();
+        list.push("", val);
+        list
+    }
     pub fn set_title(&mut self, t: &str) {
         self.title = t.to_string();
     }
     pub fn append(&mut self, other: &LineList) {
-        other
-            .list
-            .iter()
-            .for_each(|v| self.list.push(Line::new(&v.desc, v.val)));
+        self.list.extend(other.list.iter().cloned());
+    }
+
+    fn printer(&self, precision: Precision) -> Printer {
+        let mut printer = Printer::new(precision);
+        printer.set_title(&self.title);
+        self.list.iter().for_each(|v| printer.push_line(v));
+        printer
     }
-}
 
-impl Analysis {
-    pub fn round(&mut self) {
-        self.expected = self.expected.map(|v| v.roundn(10));
-        self.variance = self.variance.map(|v| v.roundn(10));
+    /// render the list in the given output format, showing values
+    /// with the given precision (JSON values are never rounded)
+    pub fn render(&self, format: Format, p: Precision) -> String {
+        let (title, list) = (&self.title, &self.list);
+        match format {
+            Format::Text => self.printer(p).to_string(),
+            Format::Json => printer::json(title, list),
+            Format::Csv => printer::csv(title, list, p),
+            Format::Tsv => printer::tsv(title, list, p),
+            Format::Markdown => printer::markdown(title, list, p),
+            Format::Latex => printer::latex(title, list, p),
+        }
     }
 }
 
-impl Display for Analysis {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let mut printer = Printer::new();
-        printer.raw_line("expected", self.expected);
-        printer.raw_line("variance", self.variance);
-        self.pdf_eval.iter().for_each(|v| printer.push_line(&v));
-        self.cdf_eval.iter().for_each(|v| printer.push_line(&v));
-        printer.set_title(&self.title);
-        printer.flush(f)
+impl From<Analysis> for LineList {
+    fn from(analysis: Analysis) -> Self {
+        let mut list = LineList::new();
+        list.set_title(&analysis.title);
+        if let Some(v) = analysis.expected {
+            list.push("expected", v);
+        }
+        if let Some(v) = analysis.variance {
+            list.push("variance", v);
+        }
+        list.list.extend(analysis.moments);
+        list.list.extend(analysis.pdf_eval);
+        list.list.extend(analysis.cdf_eval);
+        list
     }
 }
 
 impl Display for LineList {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let mut printer = Printer::new();
-        printer.set_title(&self.title);
-        self.list.iter().for_each(|v| printer.push_line(v));
-        printer.flush(f)
+        write!(f, "{}", self.printer(Precision::default()))
     }
 }
@@ -0,0 +1,89 @@
+use crate::distributions::{build, Bernoulli, Discrete, Distribution};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Discrete as Y, DiscreteCDF};
+use statrs::statistics::Distribution as X;
+
+impl Bernoulli {
+    pub fn new(p: f64) -> Result<Bernoulli> {
+        Ok(Bernoulli { p, core: build(SR::Bernoulli::new(p))? })
+    }
+}
+
+impl Distribution for Bernoulli {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ Ber({p})", p = self.p)
+    }
+    /// the smallest of the most likely values, which is also the median
+    fn median(&self) -> Option<f64> {
+        self.mode()
+    }
+    /// 1 only if a win is more likely than a loss
+    fn mode(&self) -> Option<f64> {
+        Some(match self.p > 0.5 {
+            true => 1.0,
+            false => 0.0,
+        })
+    }
+    /// -p ln p - (1-p) ln(1-p), where 0 ln 0 = 0
+    fn entropy(&self) -> Option<f64> {
+        let h = |p: f64| match p > 0.0 {
+            true => -p * p.ln(),
+            false => 0.0,
+        };
+        Some(h(self.p) + h(1.0 - self.p))
+    }
+    /// (1 - 2p)/√{p(1 - p)}
+    fn skewness(&self) -> Option<f64> {
+        let v = self.variance().filter(|v| *v > 0.0)?;
+        Some((1.0 - 2.0 * self.p) / v.sqrt())
+    }
+    /// {1 - 6p(1 - p)}/{p(1 - p)}
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let v = self.variance().filter(|v| *v > 0.0)?;
+        Some((1.0 - 6.0 * v) / v)
+    }
+    /// X^k = X for k > 0, as X is either 0 or 1
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        Some(match k {
+            0 => 1.0,
+            _ => self.p,
+        })
+    }
+    fn central_moment(&self, k: i32) -> Option<f64> {
+        let p = self.p;
+        Some((1.0 - p) * (-p).powi(k) + p * (1.0 - p).powi(k))
+    }
+}
+
+impl Discrete for Bernoulli {
+    fn pmf(&self, x: u64) -> f64 {
+        self.core.pmf(x)
+    }
+    fn cdf(&self, x: u64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: u64) -> f64 {
+        self.core.sf(x)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ Ber(p), then
+    // * E(X) = p
+    // * var(X) = p(1-p)
+    let dist = Bernoulli::new(0.3)?;
+    float_eq!(dist.mean().unwrap(), 0.3);
+    float_eq!(dist.variance().unwrap(), 0.21);
+    float_eq!(dist.pmf(1), 0.3);
+    float_eq!(dist.pmf(2), 0);
+    float_eq!(dist.cdf(0), 0.7);
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::distributions::{build, Beta, Continuous, Distribution};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::function::beta::inv_beta_reg;
+use statrs::statistics::Distribution as X;
+
+impl Beta {
+    pub fn new(a: f64, b: f64) -> Result<Beta> {
+        let core = build(SR::Beta::new(a, b))?;
+        Ok(Beta { a, b, core })
+    }
+}
+
+impl Distribution for Beta {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ Beta({a}, {b})", a = self.a, b = self.b)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(inv_beta_reg(self.a, self.b, 0.5))
+    }
+    /// none if the density is U-shaped or flat, and otherwise the end
+    /// it rises towards if it has no peak inside (0, 1)
+    fn mode(&self) -> Option<f64> {
+        let (a, b) = (self.a, self.b);
+        if a > 1.0 && b > 1.0 {
+            Some((a - 1.0) / (a + b - 2.0))
+        } else if a <= 1.0 && b >= 1.0 && a < b {
+            Some(0.0)
+        } else if a >= 1.0 && b <= 1.0 && a > b {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    /// 2(b - a)√(a + b + 1) / {(a + b + 2)√(ab)}
+    fn skewness(&self) -> Option<f64> {
+        let (a, b) = (self.a, self.b);
+        let top = 2.0 * (b - a) * (a + b + 1.0).sqrt();
+        Some(top / ((a + b + 2.0) * (a * b).sqrt()))
+    }
+    /// 6{(a - b)²(a + b + 1) - ab(a + b + 2)} / {ab(a + b + 2)(a + b + 3)}
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let (a, b) = (self.a, self.b);
+        let top = (a - b).powi(2) * (a + b + 1.0) - a * b * (a + b + 2.0);
+        Some(6.0 * top / (a * b * (a + b + 2.0) * (a + b + 3.0)))
+    }
+    /// the product of (a + i) / (a + b + i) for i = 0, ..., k - 1
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let (a, b) = (self.a, self.b);
+        Some((0..k).map(|i| (a + i as f64) / (a + b + i as f64)).product())
+    }
+}
+
+impl Continuous for Beta {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// inverse of the regularized incomplete beta function
+    fn inv_cdf(&self, x: f64) -> f64 {
+        inv_beta_reg(self.a, self.b, x)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ Beta(a, b), then
+    // * E(X) = a/(a + b)
+    // * var(X) = ab/{(a + b)²(a + b + 1)}
+    let dist = Beta::new(2.0, 5.0)?;
+    float_eq!(dist.mean().unwrap(), 0.2857142857);
+    float_eq!(dist.variance().unwrap(), 0.0255102041);
+    float_eq!(dist.pdf(0.2), 2.4576);
+    float_eq!(dist.cdf(0.2), 0.34464);
+    float_eq!(dist.inv_cdf(0.34464), 0.2);
+    float_eq!(dist.mode().unwrap(), 0.2);
+    float_eq!(dist.skewness().unwrap(), 0.5962847940);
+    assert_eq!(Beta::new(0.5, 0.5)?.mode(), None);
+    assert!(Beta::new(1.0, -1.0).is_err());
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use crate::distributions::{build, Binomial, Discrete, Distribution};
+use crate::distributions::{
+    build, peak, tail, Binomial, Discrete, Distribution,
+};
+use crate::math::binomial_pmf;
 use crate::utils::Result;
 use statrs::distribution as SR;
-use statrs::distribution::{Discrete as Y, DiscreteCDF};
 use statrs::statistics::Distribution as X;
 
 impl Binomial {
@@ -20,14 +22,61 @@ impl Distribution for Binomial {
     fn title(&self) -> String {
         format!("X ~ B({n}, {p})", n = self.n, p = self.p)
     }
+    /// the smallest of the most likely values, ⌈(n + 1)p⌉ - 1 or 0
+    fn mode(&self) -> Option<f64> {
+        Some(peak((self.n + 1) as f64 * self.p))
+    }
+    /// (1 - 2p)/√{np(1 - p)}
+    fn skewness(&self) -> Option<f64> {
+        let v = self.variance().filter(|v| *v > 0.0)?;
+        Some((1.0 - 2.0 * self.p) / v.sqrt())
+    }
+    /// {1 - 6p(1 - p)}/{np(1 - p)}
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let v = self.variance().filter(|v| *v > 0.0)?;
+        Some((1.0 - 6.0 * self.p * (1.0 - self.p)) / v)
+    }
+    by_summing!(median, entropy);
+}
+
+impl Binomial {
+    /// p.m.f.(j - 1)/p.m.f.(j)
+    fn down(&self, j: u64) -> f64 {
+        let j = j as f64;
+        j * (1.0 - self.p) / ((self.n as f64 - j + 1.0) * self.p)
+    }
+    /// p.m.f.(j + 1)/p.m.f.(j)
+    fn up(&self, j: u64) -> f64 {
+        let j = j as f64;
+        (self.n as f64 - j) * self.p / ((j + 1.0) * (1.0 - self.p))
+    }
 }
 
 impl Discrete for Binomial {
     fn pmf(&self, x: u64) -> f64 {
-        self.core.pmf(x)
+        binomial_pmf(x, self.n, self.p)
     }
+    /// by summing down from x, or 1 - sf(x) above the mode. statrs goes
+    /// through the regularized incomplete beta function, whose continued
+    /// fraction stops short for large n.
     fn cdf(&self, x: u64) -> f64 {
-        self.core.cdf(x)
+        if x >= self.n {
+            return 1.0;
+        }
+        if x as f64 > self.mode().unwrap() {
+            return 1.0 - self.sf(x);
+        }
+        tail(self.pmf(x), x, 0, |j| self.down(j)).min(1.0)
+    }
+    /// by summing up from x + 1, or 1 - cdf(x) below the mode
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            return 0.0;
+        }
+        if (x as f64) < self.mode().unwrap() {
+            return 1.0 - self.cdf(x);
+        }
+        tail(self.pmf(x + 1), x + 1, self.n, |j| self.up(j)).min(1.0)
     }
 }
 
@@ -48,5 +97,20 @@ fn test() -> Result<()> {
     float_eq!(dist.variance().unwrap(), 1.6);
     float_eq!(dist.pmf(2), 0.301989888);
     float_eq!(dist.cdf(4), 0.9672065024);
+    float_eq!(dist.mode().unwrap(), 2);
+    // skewness (1 − 2p)/√{np(1−p)}, excess kurtosis {1 − 6p(1−p)}/{np(1−p)}
+    float_eq!(dist.skewness().unwrap(), 0.4743416490);
+    float_eq!(dist.excess_kurtosis().unwrap(), 0.025);
+
+    // cdf(0) of B(1, 0.5) falls just short of 0.5, but 0 is the median
+    float_eq!(Binomial::new(1, 0.5)?.median().unwrap(), 0);
+
+    // the symmetric B(n, 0.5) has P(X <= n/2) = 1/2 + P(X = n/2)/2, where
+    // the incomplete beta function statrs takes falls short for large n
+    let dist = Binomial::new(1_000_000_000, 0.5)?;
+    let half = dist.pmf(500_000_000);
+    float_eq!(dist.cdf(500_000_000), 0.5 + half / 2.0);
+    // √{2/(πn)}{1 - 1/(4n)}, from Stirling's approximation
+    float_eq!(half / 2.523132521389377e-5, 1.0);
     Ok(())
 }
@@ -0,0 +1,125 @@
+use crate::display::LineList;
+use crate::distributions::Multinomial;
+use crate::distributions::{build, Categorical, Discrete, Distribution};
+use crate::math::Round;
+use crate::utils::{err, Result};
+use statrs::distribution as SR;
+use statrs::distribution::{Discrete as Y, DiscreteCDF};
+use statrs::statistics::Distribution as X;
+
+/// probabilities of each category, which must sum to 1
+fn check(p: &[f64]) -> Result<()> {
+    if p.is_empty() {
+        return err("At least one category is needed.");
+    }
+    if (p.iter().sum::<f64>() - 1.0).abs() > 1e-9 {
+        return err("Probabilities must sum to 1.");
+    }
+    Ok(())
+}
+
+/// "0.2, 0.3, 0.5"
+fn list(p: &[f64]) -> String {
+    p.iter().map(|v| v.roundn(10).to_string()).collect::<Vec<_>>().join(", ")
+}
+
+impl Categorical {
+    /// categories 0, 1, ..., k - 1 with probabilities p
+    pub fn new(p: &[f64]) -> Result<Categorical> {
+        check(p)?;
+        let core = build(SR::Categorical::new(p))?;
+        Ok(Categorical { p: p.to_vec(), core })
+    }
+}
+
+impl Distribution for Categorical {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ Cat({})", list(&self.p))
+    }
+    by_summing!(median, mode, entropy);
+    by_summing!(central_moment, skewness, excess_kurtosis);
+}
+
+impl Discrete for Categorical {
+    fn pmf(&self, x: u64) -> f64 {
+        self.core.pmf(x)
+    }
+    fn cdf(&self, x: u64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: u64) -> f64 {
+        self.core.sf(x)
+    }
+}
+
+impl Multinomial {
+    /// counts of each category in n independent trials, where each
+    /// trial falls in category i with probability p[i]
+    pub fn new(p: &[f64], n: u64) -> Result<Multinomial> {
+        check(p)?;
+        let core = build(SR::Multinomial::new(p, n))?;
+        Ok(Multinomial { p: p.to_vec(), n, core })
+    }
+
+    pub fn title(&self) -> String {
+        format!("X ~ M({}; {})", self.n, list(&self.p))
+    }
+
+    /// P(X1 = x1, ..., Xk = xk), which is zero unless the counts sum to n
+    pub fn pmf(&self, x: &[u64]) -> Result<f64> {
+        match x.len() == self.p.len() {
+            true => Ok(self.core.pmf(x)),
+            false => err("Each category needs exactly one count."),
+        }
+    }
+
+    /// the mean and variance of each count, and the probability of
+    /// the counts x
+    pub fn analyze(&self, x: &[u64]) -> Result<LineList> {
+        let mut list = LineList::new();
+        list.set_title(&self.title());
+        let n = self.n as f64;
+        for (i, p) in self.p.iter().enumerate() {
+            list.header(&format!("X{}", i + 1));
+            list.push("expected", n * p);
+            list.push("variance", n * p * (1.0 - p));
+        }
+        if !x.is_empty() {
+            let desc = (0..x.len())
+                .map(|i| format!("X{} = {}", i + 1, x[i]))
+                .collect::<Vec<_>>();
+            list.header("probability");
+            list.push(&format!("P({})", desc.join(", ")), self.pmf(x)?);
+        }
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ Cat(p0, ..., pk), then
+    // * P(X = i) = pi
+    // * E(X) = Σ i pi
+    let dist = Categorical::new(&[0.2, 0.3, 0.5])?;
+    float_eq!(dist.mean().unwrap(), 1.3);
+    float_eq!(dist.variance().unwrap(), 0.61);
+    float_eq!(dist.pmf(1), 0.3);
+    float_eq!(dist.cdf(1), 0.5);
+    assert!(Categorical::new(&[0.2, 0.3]).is_err());
+    assert!(Categorical::new(&[]).is_err());
+
+    // if X ~ M(n; p1, ..., pk), then
+    // * E(Xi) = n pi
+    // * P(X = x) = n!/(x1!...xk!) p1^x1 ... pk^xk
+    let dist = Multinomial::new(&[0.2, 0.3, 0.5], 6)?;
+    float_eq!(dist.pmf(&[1, 2, 3])?, 0.135);
+    float_eq!(dist.pmf(&[1, 2, 2])?, 0);
+    assert!(dist.pmf(&[1, 5]).is_err());
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::distributions::{build, Cauchy, Continuous, Distribution};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::statistics::Distribution as X;
+use std::f64::consts::PI;
+
+impl Cauchy {
+    pub fn new(x: f64, g: f64) -> Result<Cauchy> {
+        let core = build(SR::Cauchy::new(x, g))?;
+        Ok(Cauchy { x, g, core })
+    }
+}
+
+impl Distribution for Cauchy {
+    /// the mean and variance are undefined
+    fn mean(&self) -> Option<f64> {
+        None
+    }
+    fn variance(&self) -> Option<f64> {
+        None
+    }
+    fn title(&self) -> String {
+        format!("X ~ C({x}, {g})", x = self.x, g = self.g)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(self.x)
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(self.x)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    fn skewness(&self) -> Option<f64> {
+        None
+    }
+    fn excess_kurtosis(&self) -> Option<f64> {
+        None
+    }
+    /// no moments exist beyond the 0th
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        match k {
+            0 => Some(1.0),
+            _ => None,
+        }
+    }
+}
+
+impl Continuous for Cauchy {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// x₀ + γ tan(π(p - 1/2))
+    fn inv_cdf(&self, x: f64) -> f64 {
+        self.x + self.g * (PI * (x - 0.5)).tan()
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ C(x, g), then
+    // * E(X) and var(X) are undefined
+    // * P(X <= x + g) = 3/4
+    let dist = Cauchy::new(1.0, 2.0)?;
+    assert_eq!(dist.mean(), None);
+    assert_eq!(dist.variance(), None);
+    float_eq!(dist.cdf(3.0), 0.75);
+    float_eq!(dist.inv_cdf(0.75), 3);
+    float_eq!(dist.pdf(1.0), 0.5 / std::f64::consts::PI);
+    assert!(Cauchy::new(0.0, 0.0).is_err());
+    Ok(())
+}
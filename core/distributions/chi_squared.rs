@@ -1,4 +1,6 @@
-use crate::distributions::{build, ChiSquared, Continuous, Distribution};
+use crate::distributions::{
+    bisect, build, ChiSquared, Continuous, Distribution,
+};
 use crate::utils::Result;
 use statrs::distribution as SR;
 use statrs::distribution::{Continuous as Y, ContinuousCDF};
@@ -21,6 +23,29 @@ impl Distribution for ChiSquared {
     fn title(&self) -> String {
         format!("X ~ χ²({n})", n = self.freedom)
     }
+    /// by bisection, as there is no closed form
+    fn median(&self) -> Option<f64> {
+        Some(bisect(|v| self.core.cdf(v), 0.5, 0.0))
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(self.freedom.saturating_sub(2) as f64)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    /// √(8/n)
+    fn skewness(&self) -> Option<f64> {
+        Some((8.0 / self.freedom as f64).sqrt())
+    }
+    /// 12/n
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(12.0 / self.freedom as f64)
+    }
+    /// n (n + 2) ... (n + 2k - 2)
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let n = self.freedom as f64;
+        Some((0..k).map(|i| n + 2.0 * i as f64).product())
+    }
 }
 
 impl Continuous for ChiSquared {
@@ -30,8 +55,12 @@ impl Continuous for ChiSquared {
     fn cdf(&self, x: f64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// by bisection, as statrs' inverse is only good to about 1e-4
     fn inv_cdf(&self, x: f64) -> f64 {
-        self.core.inverse_cdf(x)
+        bisect(|v| self.core.cdf(v), x, 0.0)
     }
 }
 
@@ -45,6 +74,7 @@ fn test() -> Result<()> {
     float_eq!(chisq(8).mean().unwrap(), 8);
     float_eq!(chisq(8).variance().unwrap(), 16);
     float_eq!(chisq(9).cdf(1.2), 0.0011788966);
+    float_eq!(chisq(5).inv_cdf(0.95), 11.0704976935, 9);
 
     Ok(())
 }
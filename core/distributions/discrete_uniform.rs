@@ -0,0 +1,81 @@
+use crate::distributions::{build, Discrete, DiscreteUniform, Distribution};
+use crate::utils::{err, Result};
+use statrs::distribution as SR;
+use statrs::distribution::{Discrete as Y, DiscreteCDF};
+use statrs::statistics::Distribution as X;
+
+impl DiscreteUniform {
+    pub fn new(min: u64, max: u64) -> Result<DiscreteUniform> {
+        if min > max {
+            return err("The minimum cannot exceed the maximum.");
+        }
+        let core = build(SR::DiscreteUniform::new(min as i64, max as i64))?;
+        Ok(DiscreteUniform { min, max, core })
+    }
+}
+
+impl Distribution for DiscreteUniform {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ DU({a}, {b})", a = self.min, b = self.max)
+    }
+    /// the middle value, or the lower of the middle two
+    fn median(&self) -> Option<f64> {
+        Some((self.min + (self.max - self.min) / 2) as f64)
+    }
+    /// every value in {a, ..., b} is equally likely
+    fn mode(&self) -> Option<f64> {
+        match self.min == self.max {
+            true => Some(self.min as f64),
+            false => None,
+        }
+    }
+    /// ln(b - a + 1), as all b - a + 1 values are equally likely
+    fn entropy(&self) -> Option<f64> {
+        Some(((self.max - self.min + 1) as f64).ln())
+    }
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    /// -6(n² + 1)/{5(n² - 1)} over n = b - a + 1 values
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let n = (self.max - self.min + 1) as f64;
+        match n > 1.0 {
+            true => Some(-6.0 * (n * n + 1.0) / (5.0 * (n * n - 1.0))),
+            false => None,
+        }
+    }
+}
+
+impl Discrete for DiscreteUniform {
+    fn pmf(&self, x: u64) -> f64 {
+        self.core.pmf(x as i64)
+    }
+    fn cdf(&self, x: u64) -> f64 {
+        self.core.cdf(x as i64)
+    }
+    fn sf(&self, x: u64) -> f64 {
+        self.core.sf(x as i64)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ DU(a, b), then
+    // * E(X) = (a + b)/2
+    // * var(X) = {(b - a + 1)² - 1}/12
+    let dist = DiscreteUniform::new(1, 6)?;
+    float_eq!(dist.mean().unwrap(), 3.5);
+    float_eq!(dist.variance().unwrap(), 2.9166666667);
+    float_eq!(dist.pmf(3), 1.0 / 6.0);
+    float_eq!(dist.pmf(7), 0);
+    float_eq!(dist.cdf(4), 4.0 / 6.0);
+    float_eq!(dist.median().unwrap(), 3);
+    assert!(DiscreteUniform::new(6, 1).is_err());
+    Ok(())
+}
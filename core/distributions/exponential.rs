@@ -1,8 +1,9 @@
-use crate::distributions::{build, Exponential, Continuous, Distribution};
+use crate::distributions::{build, Continuous, Distribution, Exponential};
 use crate::utils::Result;
 use statrs::distribution as SR;
 use statrs::distribution::{Continuous as Y, ContinuousCDF};
 use statrs::statistics::Distribution as X;
+use std::f64::consts::LN_2;
 
 impl Exponential {
     pub fn new(l: f64) -> Result<Exponential> {
@@ -20,6 +21,25 @@ impl Distribution for Exponential {
     fn title(&self) -> String {
         format!("X ~ Exp({l})", l = self.l)
     }
+    fn median(&self) -> Option<f64> {
+        Some(LN_2 / self.l)
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    fn skewness(&self) -> Option<f64> {
+        Some(2.0)
+    }
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0)
+    }
+    /// k! / l^k
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        Some((1..=k).map(|i| i as f64 / self.l).product())
+    }
 }
 
 impl Continuous for Exponential {
@@ -29,8 +49,12 @@ impl Continuous for Exponential {
     fn cdf(&self, x: f64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// -ln(1 - p)/λ
     fn inv_cdf(&self, x: f64) -> f64 {
-        self.core.inverse_cdf(x)
+        -(1.0 - x).ln() / self.l
     }
 }
 
@@ -43,5 +67,6 @@ fn test() -> Result<()> {
     float_eq!(dist.mean().unwrap(), 5);
     float_eq!(dist.variance().unwrap(), 25);
     float_eq!(dist.cdf(8.0), 0.798103482005);
+    float_eq!(dist.inv_cdf(0.798103482005), 8);
     Ok(())
 }
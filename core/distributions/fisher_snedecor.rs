@@ -1,4 +1,7 @@
-use crate::distributions::{build, FisherSnedecor, Continuous, Distribution};
+use crate::distributions::numerical;
+use crate::distributions::{
+    bisect, build, Continuous, Distribution, FisherSnedecor,
+};
 use crate::utils::Result;
 use statrs::distribution as SR;
 use statrs::distribution::{Continuous as Y, ContinuousCDF};
@@ -21,6 +24,55 @@ impl Distribution for FisherSnedecor {
     fn title(&self) -> String {
         format!("X ~ F({f1}, {f2})", f1 = self.f1, f2 = self.f2)
     }
+    /// by bisection, as there is no closed form
+    fn median(&self) -> Option<f64> {
+        Some(bisect(|v| self.core.cdf(v), 0.5, 0.0))
+    }
+    fn mode(&self) -> Option<f64> {
+        let (m, n) = (self.f1 as f64, self.f2 as f64);
+        match m > 2.0 {
+            true => Some((m - 2.0) / m * n / (n + 2.0)),
+            false => Some(0.0),
+        }
+    }
+    /// by numerical integration, as statrs has no closed form
+    fn entropy(&self) -> Option<f64> {
+        numerical::integral(self, |x| -self.pdf(x).ln())
+    }
+    /// only exists for n > 6:
+    /// (2m + n - 2)√{8(n - 4)} / {(n - 6)√(m(m + n - 2))}
+    fn skewness(&self) -> Option<f64> {
+        let (m, n) = (self.f1 as f64, self.f2 as f64);
+        if n <= 6.0 {
+            return None;
+        }
+        let top = (2.0 * m + n - 2.0) * (8.0 * (n - 4.0)).sqrt();
+        Some(top / ((n - 6.0) * (m * (m + n - 2.0)).sqrt()))
+    }
+    /// only exists for n > 8:
+    /// 12{m(5n - 22)(m + n - 2) + (n - 4)(n - 2)²}
+    ///     / {m(n - 6)(n - 8)(m + n - 2)}
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let (m, n) = (self.f1 as f64, self.f2 as f64);
+        if n <= 8.0 {
+            return None;
+        }
+        let top = m * (5.0 * n - 22.0) * (m + n - 2.0)
+            + (n - 4.0) * (n - 2.0).powi(2);
+        Some(12.0 * top / (m * (n - 6.0) * (n - 8.0) * (m + n - 2.0)))
+    }
+    /// only exists for 2k < n: (n/m)^k Γ(m/2 + k) Γ(n/2 - k) / Γ(m/2) Γ(n/2)
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let (m, n) = (self.f1 as f64, self.f2 as f64);
+        if 2.0 * k as f64 >= n {
+            return None;
+        }
+        let term = |i: i32| {
+            let i = i as f64;
+            n / m * (m / 2.0 + i) / (n / 2.0 - i - 1.0)
+        };
+        Some((0..k).map(term).product())
+    }
 }
 
 impl Continuous for FisherSnedecor {
@@ -30,6 +82,9 @@ impl Continuous for FisherSnedecor {
     fn cdf(&self, x: f64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
     fn inv_cdf(&self, x: f64) -> f64 {
         self.core.inverse_cdf(x)
     }
@@ -49,10 +104,10 @@ fn test() -> Result<()> {
         };
     }
 
-    assert_eq!(FisherSnedecor::new(0, 0).is_err(), true);
-    assert_eq!(FisherSnedecor::new(0, 1).is_err(), true);
-    assert_eq!(FisherSnedecor::new(1, 0).is_err(), true);
-    assert_eq!(FisherSnedecor::new(1, 1).is_err(), false);
+    assert!(FisherSnedecor::new(0, 0).is_err());
+    assert!(FisherSnedecor::new(0, 1).is_err());
+    assert!(FisherSnedecor::new(1, 0).is_err());
+    assert!(FisherSnedecor::new(1, 1).is_ok());
 
     ftest!(1, 1, None, None);
     ftest!(1, 2, None, None);
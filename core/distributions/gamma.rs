@@ -0,0 +1,79 @@
+use crate::distributions::{bisect, build, Continuous, Distribution, Gamma};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::statistics::Distribution as X;
+
+impl Gamma {
+    pub fn new(a: f64, b: f64) -> Result<Gamma> {
+        let core = build(SR::Gamma::new(a, b))?;
+        Ok(Gamma { a, b, core })
+    }
+}
+
+impl Distribution for Gamma {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ Gamma({a}, {b})", a = self.a, b = self.b)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(self.inv_cdf(0.5))
+    }
+    /// for a < 1, the density has a pole at 0
+    fn mode(&self) -> Option<f64> {
+        Some((self.a - 1.0).max(0.0) / self.b)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    /// 2/√a
+    fn skewness(&self) -> Option<f64> {
+        Some(2.0 / self.a.sqrt())
+    }
+    /// 6/a
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(6.0 / self.a)
+    }
+    /// a (a + 1) ... (a + k - 1) / b^k
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        Some((0..k).map(|i| (self.a + i as f64) / self.b).product())
+    }
+}
+
+impl Continuous for Gamma {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// no closed form, so bisect to full precision instead of using
+    /// statrs' coarser default
+    fn inv_cdf(&self, x: f64) -> f64 {
+        bisect(|v| self.core.cdf(v), x, 0.0)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ Gamma(a, b) with shape a and rate b, then
+    // * E(X) = a/b
+    // * var(X) = a/b²
+    let dist = Gamma::new(2.0, 0.5)?;
+    float_eq!(dist.mean().unwrap(), 4);
+    float_eq!(dist.variance().unwrap(), 8);
+    float_eq!(dist.pdf(3.0), 0.1673476201);
+    float_eq!(dist.cdf(3.0), 0.4421745996);
+    // the 90th percentile of χ²(4) = Gamma(2, 0.5)
+    float_eq!(dist.inv_cdf(0.9), 7.7794403397);
+    assert!(Gamma::new(0.0, 1.0).is_err());
+    Ok(())
+}
@@ -1,7 +1,7 @@
-use crate::distributions::{build, Geometric, Discrete, Distribution};
+use crate::distributions::{build, Discrete, Distribution, Geometric};
 use crate::utils::Result;
 use statrs::distribution as SR;
-use statrs::distribution::{Discrete as Y, DiscreteCDF};
+use statrs::distribution::DiscreteCDF;
 use statrs::statistics::Distribution as X;
 
 impl Geometric {
@@ -20,15 +20,48 @@ impl Distribution for Geometric {
     fn title(&self) -> String {
         format!("X ~ G({p})", p = self.p)
     }
+    /// the first try is always the most likely to be the first win
+    fn mode(&self) -> Option<f64> {
+        Some(1.0)
+    }
+    /// {-(1-p) ln(1-p) - p ln p}/p, which is 0 when the first try
+    /// always wins
+    fn entropy(&self) -> Option<f64> {
+        let (p, q) = (self.p, 1.0 - self.p);
+        match q > 0.0 {
+            true => Some(-(q * q.ln() + p * p.ln()) / p),
+            false => Some(0.0),
+        }
+    }
+    /// (2 - p)/√(1 - p)
+    fn skewness(&self) -> Option<f64> {
+        let q = Some(1.0 - self.p).filter(|q| *q > 0.0)?;
+        Some((2.0 - self.p) / q.sqrt())
+    }
+    /// 6 + p²/(1 - p)
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let q = Some(1.0 - self.p).filter(|q| *q > 0.0)?;
+        Some(6.0 + self.p * self.p / q)
+    }
+    by_summing!(median);
 }
 
 impl Discrete for Geometric {
+    /// p(1 - p)^(x - 1), through logarithms, as statrs takes the power
+    /// of an i32 that overflows past x = 2³¹
     fn pmf(&self, x: u64) -> f64 {
-        self.core.pmf(x)
+        match x {
+            0 => 0.0,
+            1 => self.p,
+            _ => self.p * ((x - 1) as f64 * (-self.p).ln_1p()).exp(),
+        }
     }
     fn cdf(&self, x: u64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: u64) -> f64 {
+        self.core.sf(x)
+    }
 }
 
 #[test]
@@ -42,5 +75,12 @@ fn test() -> Result<()> {
     float_eq!(dist.mean().unwrap(), 20);
     float_eq!(dist.variance().unwrap(), 380);
     float_eq!(dist.pmf(5), 0.0407253125);
+    // far past x = 2³¹, where statrs overflows
+    let far = Geometric::new(1e-9)?.pmf(30_000_000_001);
+    float_eq!(far / (1e-9 * (-30.0 - 1.5e-8f64).exp()), 1.0);
+    // the closed-form entropy agrees with -Σ P(X = x) ln P(X = x)
+    use crate::distributions::numerical::sum;
+    let sum = sum(&dist, |x| -dist.pmf(x as u64).ln());
+    float_eq!(dist.entropy().unwrap(), sum.unwrap());
     Ok(())
 }
@@ -0,0 +1,152 @@
+use crate::distributions::numerical;
+use crate::distributions::{
+    build, peak, tail, Discrete, Distribution, Hypergeometric,
+};
+use crate::math::binomial_pmf;
+use crate::utils::{err, Result};
+use statrs::distribution as SR;
+use statrs::statistics::Distribution as X;
+
+impl Hypergeometric {
+    pub fn new(m: u64, k: u64, n: u64) -> Result<Hypergeometric> {
+        if k > m {
+            return err("Successes cannot exceed the population size.");
+        }
+        if n > m {
+            return err("Draws cannot exceed the population size.");
+        }
+        let core = build(SR::Hypergeometric::new(m, k, n))?;
+        Ok(Hypergeometric { m, k, n, core })
+    }
+}
+
+impl Distribution for Hypergeometric {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ H({m}, {k}, {n})", m = self.m, k = self.k, n = self.n)
+    }
+    /// the smallest of the most likely values, ⌈(n + 1)(K + 1)/(N + 2)⌉ - 1,
+    /// or the smallest possible value
+    fn mode(&self) -> Option<f64> {
+        let (m, k, n) = (self.m as f64, self.k as f64, self.n as f64);
+        let lowest = (n + k - m).max(0.0);
+        Some(peak((n + 1.0) * (k + 1.0) / (m + 2.0)).max(lowest))
+    }
+    /// (N - 2K)(N - 2n)√(N - 1) / {(N - 2)√(nK(N - K)(N - n))}, or by
+    /// summing for N = 2, where this divides by 0
+    fn skewness(&self) -> Option<f64> {
+        let (m, k, n) = (self.m as f64, self.k as f64, self.n as f64);
+        let spread = n * k * (m - k) * (m - n);
+        if spread == 0.0 {
+            return None;
+        }
+        if m < 3.0 {
+            let mu = self.mean()?;
+            let c3 = numerical::sum(self, |x| (x - mu).powi(3))?;
+            return Some(c3 / self.variance()?.powf(1.5));
+        }
+        let top = (m - 2.0 * k) * (m - 2.0 * n) * (m - 1.0).sqrt();
+        Some(top / ((m - 2.0) * spread.sqrt()))
+    }
+    /// [(N - 1)N²{N(N + 1) - 6K(N - K) - 6n(N - n)}
+    ///     + 6nK(N - K)(N - n)(5N - 6)] / {nK(N - K)(N - n)(N - 2)(N - 3)},
+    /// or by summing for N < 4, where this divides by 0
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let (m, k, n) = (self.m as f64, self.k as f64, self.n as f64);
+        let spread = n * k * (m - k) * (m - n);
+        if spread == 0.0 {
+            return None;
+        }
+        if m < 4.0 {
+            let mu = self.mean()?;
+            let c4 = numerical::sum(self, |x| (x - mu).powi(4))?;
+            return Some(c4 / self.variance()?.powi(2) - 3.0);
+        }
+        let inner = m * (m + 1.0) - 6.0 * k * (m - k) - 6.0 * n * (m - n);
+        let top = (m - 1.0) * m * m * inner + 6.0 * spread * (5.0 * m - 6.0);
+        Some(top / (spread * (m - 2.0) * (m - 3.0)))
+    }
+    by_summing!(median, entropy);
+}
+
+impl Hypergeometric {
+    /// the smallest and largest possible values
+    fn range(&self) -> (u64, u64) {
+        ((self.n + self.k).saturating_sub(self.m), self.k.min(self.n))
+    }
+    /// p.m.f.(j - 1)/p.m.f.(j)
+    fn down(&self, j: u64) -> f64 {
+        let (m, k, n, j) =
+            (self.m as f64, self.k as f64, self.n as f64, j as f64);
+        j * (m - k - n + j) / ((k - j + 1.0) * (n - j + 1.0))
+    }
+    /// p.m.f.(j + 1)/p.m.f.(j)
+    fn up(&self, j: u64) -> f64 {
+        let (m, k, n, j) =
+            (self.m as f64, self.k as f64, self.n as f64, j as f64);
+        (k - j) * (n - j) / ((j + 1.0) * (m - k - n + j + 1.0))
+    }
+}
+
+impl Discrete for Hypergeometric {
+    /// as B(K, p)(x) B(N - K, p)(n - x) / B(N, p)(n) for p = n/N, from
+    /// precise binomial p.m.f.s, as the binomial coefficients statrs
+    /// divides overflow, and their logarithms lose precision, for large N
+    fn pmf(&self, x: u64) -> f64 {
+        let (lo, hi) = self.range();
+        if x < lo || x > hi {
+            return 0.0;
+        }
+        let (m, k, n) = (self.m, self.k, self.n);
+        let p = n as f64 / m as f64;
+        binomial_pmf(x, k, p) * binomial_pmf(n - x, m - k, p)
+            / binomial_pmf(n, m, p)
+    }
+    /// by summing down from x, or 1 - sf(x) above the mode
+    fn cdf(&self, x: u64) -> f64 {
+        let (lo, hi) = self.range();
+        if x >= hi {
+            return 1.0;
+        }
+        if x as f64 > self.mode().unwrap() {
+            return 1.0 - self.sf(x);
+        }
+        tail(self.pmf(x), x, lo, |j| self.down(j)).min(1.0)
+    }
+    /// by summing up from x + 1, or 1 - cdf(x) below the mode
+    fn sf(&self, x: u64) -> f64 {
+        let (lo, hi) = self.range();
+        if x >= hi {
+            return 0.0;
+        }
+        if x < lo {
+            return 1.0;
+        }
+        if (x as f64) < self.mode().unwrap() {
+            return 1.0 - self.cdf(x);
+        }
+        tail(self.pmf(x + 1), x + 1, hi, |j| self.up(j)).min(1.0)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ H(N, K, n), then
+    // * E(X) = nK/N
+    // * var(X) = n(K/N)(1 - K/N)(N - n)/(N - 1)
+    // * X is the number of wins in n draws without replacement from
+    //   a population of N with K wins
+    let dist = Hypergeometric::new(20, 7, 5)?;
+    float_eq!(dist.mean().unwrap(), 1.75);
+    float_eq!(dist.variance().unwrap(), 0.8980263158);
+    float_eq!(dist.pmf(2), 0.3873839009);
+    float_eq!(dist.cdf(1), 0.4058307534);
+    assert!(Hypergeometric::new(20, 21, 5).is_err());
+    assert!(Hypergeometric::new(20, 7, 21).is_err());
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use crate::distributions::{build, Continuous, Distribution, Laplace};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::function::factorial::{binomial, factorial};
+use statrs::statistics::Distribution as X;
+
+impl Laplace {
+    pub fn new(m: f64, b: f64) -> Result<Laplace> {
+        let core = build(SR::Laplace::new(m, b))?;
+        Ok(Laplace { m, b, core })
+    }
+}
+
+impl Distribution for Laplace {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ L({m}, {b})", m = self.m, b = self.b)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(self.m)
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(self.m)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(3.0)
+    }
+    /// Σ C(k, j) m^(k-j) b^j j! over even j, the odd moments of
+    /// X - m being 0
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let term = |j: i32| {
+            let c = binomial(k as u64, j as u64);
+            c * self.m.powi(k - j) * self.b.powi(j) * factorial(j as u64)
+        };
+        Some((0..=k).step_by(2).map(term).sum())
+    }
+}
+
+impl Continuous for Laplace {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    fn inv_cdf(&self, x: f64) -> f64 {
+        self.core.inverse_cdf(x)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ L(m, b), then
+    // * E(X) = m
+    // * var(X) = 2b²
+    let dist = Laplace::new(0.0, 1.0)?;
+    float_eq!(dist.mean().unwrap(), 0);
+    float_eq!(dist.variance().unwrap(), 2);
+    float_eq!(dist.cdf(1.0), 0.8160602794);
+    float_eq!(dist.inv_cdf(0.9), 5f64.ln());
+    assert!(Laplace::new(0.0, -1.0).is_err());
+    Ok(())
+}
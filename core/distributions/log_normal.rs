@@ -0,0 +1,82 @@
+use crate::distributions::{build, Continuous, Distribution, LogNormal};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::function::erf::erfc_inv;
+use statrs::statistics::Distribution as X;
+use std::f64::consts::SQRT_2;
+
+impl LogNormal {
+    pub fn new(m: f64, s: f64) -> Result<LogNormal> {
+        let core = build(SR::LogNormal::new(m, s))?;
+        Ok(LogNormal { m, s, core })
+    }
+}
+
+impl Distribution for LogNormal {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ LogN({m}, {s}²)", m = self.m, s = self.s)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(self.m.exp())
+    }
+    fn mode(&self) -> Option<f64> {
+        Some((self.m - self.s * self.s).exp())
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    /// (w + 3)√w, where w = exp(s²) - 1
+    fn skewness(&self) -> Option<f64> {
+        let w = (self.s * self.s).exp_m1();
+        Some((w + 3.0) * w.sqrt())
+    }
+    /// exp(4s²) + 2exp(3s²) + 3exp(2s²) - 6, written in w = exp(s²) - 1
+    /// as 16w + 15w² + 6w³ + w⁴ so that it holds its precision for small s
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let w = (self.s * self.s).exp_m1();
+        Some(w * (16.0 + w * (15.0 + w * (6.0 + w))))
+    }
+    /// exp(km + k²s²/2)
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let k = k as f64;
+        Some((k * self.m + k * k * self.s * self.s / 2.0).exp())
+    }
+}
+
+impl Continuous for LogNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// closed form, from the quantile of the underlying normal
+    fn inv_cdf(&self, x: f64) -> f64 {
+        let z = -SQRT_2 * erfc_inv(2.0 * x);
+        (self.m + self.s * z).exp()
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ LogN(m, s²), then
+    // * ln(X) ~ N(m, s²)
+    // * E(X) = exp(m + s²/2)
+    let dist = LogNormal::new(0.0, 1.0)?;
+    float_eq!(dist.mean().unwrap(), 0.5f64.exp());
+    float_eq!(dist.cdf(1.0), 0.5);
+    float_eq!(dist.cdf(2.0), 0.7558914042);
+    float_eq!(dist.inv_cdf(0.7558914042), 2);
+    assert!(LogNormal::new(0.0, 0.0).is_err());
+    Ok(())
+}
@@ -1,19 +1,77 @@
 use crate::utils::Result;
 use statrs::distribution as SR;
+use statrs::function::factorial::binomial;
+
+/// Distribution methods that a discrete distribution finds one value at
+/// a time, named as in `by_summing!(median, mode, entropy)` within its
+/// `impl Distribution`, for those of its methods without a closed form
+macro_rules! by_summing {
+    ($($method:ident),* $(,)?) => {
+        $(by_summing!(@$method);)*
+    };
+    (@median) => {
+        fn median(&self) -> Option<f64> {
+            Some($crate::distributions::numerical::median(self))
+        }
+    };
+    (@mode) => {
+        fn mode(&self) -> Option<f64> {
+            Some($crate::distributions::numerical::mode(self))
+        }
+    };
+    (@entropy) => {
+        fn entropy(&self) -> Option<f64> {
+            use $crate::distributions::{numerical, Discrete};
+            numerical::sum(self, |x| -self.pmf(x as u64).ln())
+        }
+    };
+    (@central_moment) => {
+        fn central_moment(&self, k: i32) -> Option<f64> {
+            let m = self.mean()?;
+            $crate::distributions::numerical::sum(self, |x| (x - m).powi(k))
+        }
+    };
+    (@skewness) => {
+        fn skewness(&self) -> Option<f64> {
+            let v = self.variance().filter(|v| *v > 0.0)?;
+            Some(self.central_moment(3)? / v.powf(1.5))
+        }
+    };
+    (@excess_kurtosis) => {
+        fn excess_kurtosis(&self) -> Option<f64> {
+            let v = self.variance().filter(|v| *v > 0.0)?;
+            Some(self.central_moment(4)? / (v * v) - 3.0)
+        }
+    };
+}
 
 // discrete
+mod bernoulli;
 mod binomial;
+mod categorical;
+mod discrete_uniform;
 mod geometric;
+mod hypergeometric;
 mod negatve_binomial;
 mod poisson;
+mod tabulated;
 
 // continuous
+mod beta;
+mod cauchy;
 mod chi_squared;
 mod exponential;
 mod fisher_snedecor;
+mod gamma;
+mod laplace;
+mod log_normal;
 mod normal;
+mod pareto;
 mod students_t;
 mod uniform;
+mod weibull;
+
+pub mod numerical;
 
 pub struct Binomial {
     core: SR::Binomial,
@@ -24,7 +82,6 @@ pub struct Binomial {
 }
 
 pub struct NegativeBinomial {
-    core: SR::NegativeBinomial,
     /// required number of wins
     pub k: u64,
     /// win-rate
@@ -43,6 +100,52 @@ pub struct Poisson {
     pub l: f64,
 }
 
+pub struct Hypergeometric {
+    core: SR::Hypergeometric,
+    /// population size
+    pub m: u64,
+    /// number of wins in the population
+    pub k: u64,
+    /// number of draws
+    pub n: u64,
+}
+
+pub struct DiscreteUniform {
+    core: SR::DiscreteUniform,
+    pub min: u64,
+    pub max: u64,
+}
+
+pub struct Bernoulli {
+    core: SR::Bernoulli,
+    /// win-rate
+    pub p: f64,
+}
+
+pub struct Categorical {
+    core: SR::Categorical,
+    /// probability of each category 0, 1, ..., k - 1
+    pub p: Vec<f64>,
+}
+
+/// not a Discrete distribution, since its values are vectors of counts
+pub struct Multinomial {
+    core: SR::Multinomial,
+    /// probability of each category
+    pub p: Vec<f64>,
+    /// number of trials
+    pub n: u64,
+}
+
+/// a distribution given as a table of values and their probabilities,
+/// such as from a file of <value> <probability> lines
+pub struct Tabulated {
+    /// where the table came from
+    name: String,
+    /// (value, probability) pairs, in ascending order of value
+    pmf: Vec<(u64, f64)>,
+}
+
 // continuous distributions
 pub struct Uniform {
     core: SR::Uniform,
@@ -63,7 +166,7 @@ pub struct Normal {
 
 pub struct StudentsT {
     core: SR::StudentsT,
-    freedom: u64,
+    freedom: f64,
 }
 
 pub struct ChiSquared {
@@ -77,23 +180,172 @@ pub struct FisherSnedecor {
     f2: u64,
 }
 
+pub struct Gamma {
+    core: SR::Gamma,
+    /// shape
+    a: f64,
+    /// rate
+    b: f64,
+}
+
+pub struct Beta {
+    core: SR::Beta,
+    a: f64,
+    b: f64,
+}
+
+pub struct LogNormal {
+    core: SR::LogNormal,
+    /// mean of ln(X)
+    m: f64,
+    /// std.dev of ln(X)
+    s: f64,
+}
+
+pub struct Weibull {
+    core: SR::Weibull,
+    /// shape
+    k: f64,
+    /// scale
+    l: f64,
+}
+
+pub struct Cauchy {
+    core: SR::Cauchy,
+    /// location
+    x: f64,
+    /// scale
+    g: f64,
+}
+
+pub struct Laplace {
+    core: SR::Laplace,
+    /// location
+    m: f64,
+    /// scale
+    b: f64,
+}
+
+pub struct Pareto {
+    core: SR::Pareto,
+    /// scale, the smallest possible value
+    m: f64,
+    /// shape
+    a: f64,
+}
+
 pub trait Distribution {
     fn mean(&self) -> Option<f64>;
     fn variance(&self) -> Option<f64>;
     fn title(&self) -> String;
+    fn median(&self) -> Option<f64>;
+    /// the most likely value, or the peak of the density
+    fn mode(&self) -> Option<f64>;
+    /// in nats
+    fn entropy(&self) -> Option<f64>;
+    /// E((X - μ)³)/σ³
+    fn skewness(&self) -> Option<f64>;
+    /// E((X - μ)⁴)/σ⁴ - 3
+    fn excess_kurtosis(&self) -> Option<f64>;
+
+    /// E((X - μ)^k) for k up to 4, from the variance, skewness and
+    /// excess kurtosis. Working from the mean outwards keeps the
+    /// precision that expanding into raw moments loses to a large mean.
+    /// All are 0 past the first when X is constant, though the skewness
+    /// and excess kurtosis are not defined.
+    fn central_moment(&self, k: i32) -> Option<f64> {
+        let v = self.variance()?;
+        match k {
+            0 => Some(1.0),
+            1 => Some(0.0),
+            _ if v == 0.0 && k <= 4 => Some(0.0),
+            2 => Some(v),
+            3 => Some(self.skewness()? * v.powf(1.5)),
+            4 => Some((self.excess_kurtosis()? + 3.0) * v * v),
+            _ => None,
+        }
+    }
+    /// E(X^k), by expanding X^k = {μ + (X - μ)}^k into central moments
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let m = self.mean()?;
+        (0..=k)
+            .map(|j| {
+                let c = binomial(k as u64, j as u64);
+                Some(c * m.powi(k - j) * self.central_moment(j)?)
+            })
+            .sum()
+    }
 }
 
 pub trait Discrete {
     fn pmf(&self, x: u64) -> f64;
     fn cdf(&self, x: u64) -> f64;
+    /// P(X > x), which keeps its precision far out in the upper tail,
+    /// where 1 - cdf(x) cancels to 0
+    fn sf(&self, x: u64) -> f64;
+    /// every value with any probability, in ascending order, for
+    /// distributions on a few values scattered far apart, so that sums
+    /// over them need not step through the gaps
+    fn support(&self) -> Option<Vec<u64>> {
+        None
+    }
 }
 
 pub trait Continuous {
     fn pdf(&self, x: f64) -> f64;
     fn cdf(&self, x: f64) -> f64;
+    /// P(X > x), which keeps its precision far out in the upper tail,
+    /// where 1 - cdf(x) cancels to 0
+    fn sf(&self, x: f64) -> f64;
+    /// the x with cdf(x) = p, written out where there is a closed form,
+    /// as statrs otherwise falls back on a less precise bisection
     fn inv_cdf(&self, x: f64) -> f64;
 }
 
+/// the smallest mode of a distribution on the integers whose p.m.f.
+/// rises up to ⌈c⌉ - 1 and falls after it, tying ⌈c⌉ - 1 with c only
+/// when c is whole, which is ⌈c⌉ - 1, or 0 if c is not positive
+fn peak(c: f64) -> f64 {
+    (c.ceil() - 1.0).max(0.0)
+}
+
+/// Σ p.m.f. from x, whose mass is `first`, to `last` either way, where
+/// `next(j)` is p.m.f.(next value)/p.m.f.(j). Summing a tail away from
+/// the mode, the terms only shrink, so the sum stops once they no longer
+/// count, rather than visiting every value as statrs does.
+fn tail<F: Fn(u64) -> f64>(first: f64, x: u64, last: u64, next: F) -> f64 {
+    let (mut j, mut term, mut total) = (x, first, first);
+    while j != last && term > total * f64::EPSILON / 4.0 {
+        term *= next(j);
+        j = if last > x { j + 1 } else { j - 1 };
+        total += term;
+    }
+    total
+}
+
+/// smallest x >= lo with cdf(x) >= p, by bisection to full precision,
+/// for distributions without a closed-form inverse
+fn bisect<F: Fn(f64) -> f64>(cdf: F, p: f64, lo: f64) -> f64 {
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let (mut lo, mut hi) = (lo, lo + 1.0);
+    while cdf(hi) < p {
+        (lo, hi) = (hi, lo + 2.0 * (hi - lo));
+    }
+    // stop once the midpoint can no longer be told apart from the ends
+    loop {
+        let mid = lo + (hi - lo) / 2.0;
+        if mid <= lo || mid >= hi {
+            return hi;
+        }
+        match cdf(mid) < p {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+}
+
 fn build<T>(r: std::result::Result<T, statrs::StatsError>) -> Result<T> {
     r.map_err(|v| v.to_string())
 }
@@ -1,12 +1,14 @@
-use crate::distributions::{build, Discrete, Distribution, NegativeBinomial};
+use crate::distributions::{
+    build, peak, Binomial, Discrete, Distribution, NegativeBinomial,
+};
+use crate::math::binomial_pmf;
 use crate::utils::Result;
 use statrs::distribution as SR;
-use statrs::distribution::{Discrete as Y, DiscreteCDF};
 
 impl NegativeBinomial {
     pub fn new(k: u64, p: f64) -> Result<NegativeBinomial> {
-        let core = build(SR::NegativeBinomial::new(k as f64, p))?;
-        Ok(NegativeBinomial { k, p, core })
+        build(SR::NegativeBinomial::new(k as f64, p))?;
+        Ok(NegativeBinomial { k, p })
     }
 }
 
@@ -21,14 +23,49 @@ impl Distribution for NegativeBinomial {
     fn title(&self) -> String {
         format!("X ~ NB({k}, {p})", k = self.k, p = self.p)
     }
+    /// the smallest of the most likely values, k more than the most
+    /// likely number of losses, ⌈(k - 1)(1 - p)/p⌉ - 1 or 0
+    fn mode(&self) -> Option<f64> {
+        let (k, p) = (self.k as f64, self.p);
+        Some(k + peak((k - 1.0) * (1.0 - p) / p))
+    }
+    /// (2 - p)/√{k(1 - p)}
+    fn skewness(&self) -> Option<f64> {
+        let kq = Some(self.k as f64 * (1.0 - self.p)).filter(|v| *v > 0.0)?;
+        Some((2.0 - self.p) / kq.sqrt())
+    }
+    /// 6/k + p²/{k(1 - p)}
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let kq = Some(self.k as f64 * (1.0 - self.p)).filter(|v| *v > 0.0)?;
+        Some(6.0 / self.k as f64 + self.p * self.p / kq)
+    }
+    by_summing!(median, entropy);
 }
 
 impl Discrete for NegativeBinomial {
+    /// (k/x) B(x, p)(k), from a precise binomial p.m.f., as the log gamma
+    /// differences statrs takes lose precision for large x
     fn pmf(&self, x: u64) -> f64 {
-        x.checked_sub(self.k).map(|v| self.core.pmf(v)).unwrap_or(0.0)
+        match x {
+            _ if x < self.k => 0.0,
+            0 => 1.0,
+            _ => self.k as f64 / x as f64 * binomial_pmf(self.k, x, self.p),
+        }
     }
+    /// as at most x trials bring k wins when B(x, p) >= k. statrs goes
+    /// through the regularized incomplete beta function, whose continued
+    /// fraction stops short for large k.
     fn cdf(&self, x: u64) -> f64 {
-        x.checked_sub(self.k).map(|v| self.core.cdf(v)).unwrap_or(0.0)
+        match self.k {
+            0 => 1.0,
+            k => Binomial::new(x, self.p).unwrap().sf(k - 1),
+        }
+    }
+    fn sf(&self, x: u64) -> f64 {
+        match self.k {
+            0 => 0.0,
+            k => Binomial::new(x, self.p).unwrap().cdf(k - 1),
+        }
     }
 }
 
@@ -2,6 +2,7 @@ use crate::distributions::{build, Continuous, Distribution, Normal};
 use crate::utils::Result;
 use statrs::distribution as SR;
 use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::function::factorial::binomial;
 use statrs::statistics::Distribution as X;
 
 impl Normal {
@@ -21,6 +22,31 @@ impl Distribution for Normal {
     fn title(&self) -> String {
         format!("X ~ N({m}, {s}²)", m = self.m, s = self.s)
     }
+    fn median(&self) -> Option<f64> {
+        Some(self.m)
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(self.m)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    /// Σ C(k, j) m^(k-j) s^j E(Z^j), where Z ~ N(0, 1) and E(Z^j) is
+    /// (j - 1)(j - 3)...1 for even j, and 0 for odd j
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let z = |j: i32| (1..j).step_by(2).map(|i| i as f64).product::<f64>();
+        let term = |j: i32| {
+            let c = binomial(k as u64, j as u64);
+            c * self.m.powi(k - j) * self.s.powi(j) * z(j)
+        };
+        Some((0..=k).step_by(2).map(term).sum())
+    }
 }
 
 impl Continuous for Normal {
@@ -30,6 +56,9 @@ impl Continuous for Normal {
     fn cdf(&self, x: f64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
     fn inv_cdf(&self, x: f64) -> f64 {
         self.core.inverse_cdf(x)
     }
@@ -44,5 +73,10 @@ fn test() -> Result<()> {
     float_eq!(dist.mean().unwrap(), 10);
     float_eq!(dist.variance().unwrap(), 25);
     float_eq!(dist.cdf(8.0), 0.344578258389);
+    // * E(X²) = m² + s², with no skew or excess kurtosis
+    float_eq!(dist.median().unwrap(), 10);
+    float_eq!(dist.raw_moment(2).unwrap(), 125);
+    float_eq!(dist.skewness().unwrap(), 0);
+    float_eq!(dist.excess_kurtosis().unwrap(), 0);
     Ok(())
 }
@@ -0,0 +1,245 @@
+//! Expectations E[h(X)], by summing over the values of a discrete
+//! distribution or by integrating over a continuous one, for
+//! quantities without a closed form.
+use crate::distributions::{Continuous, Discrete, Distribution};
+use crate::inverse::InvertDiscrete;
+use crate::math::simpson;
+
+/// probability beyond the outermost quantiles
+const TAIL: f64 = 1e-12;
+
+/// Simpson intervals between each pair of neighbouring quantiles
+const INTERVALS: usize = 1000;
+
+/// most terms of a sum between the outermost quantiles. A distribution
+/// spread over more values is summed in blocks of stride values, each
+/// taken at its middle value, which its smooth p.m.f. allows. Also the
+/// most terms past the 1 - TAIL quantile after which a sum that still
+/// changes is taken to diverge.
+const TERMS: u64 = 1_000_000;
+
+/// adds v to the total, carrying what rounding loses as well, after
+/// Neumaier, as a sum of a million terms would otherwise lose digits
+fn add((total, carry): &mut (f64, f64), v: f64) {
+    let next = *total + v;
+    *carry += match total.abs() >= v.abs() {
+        true => (*total - next) + v,
+        false => (v - next) + *total,
+    };
+    *total = next;
+}
+
+/// Σ h(x) P(X = x), from where TAIL of the probability lies below to
+/// where all but TAIL is covered, and on out either way until the rest
+/// of the tail no longer counts, or over the support if the distribution
+/// lists it. None if the sum diverges, which shows as the total
+/// overflowing or the terms still counting TERMS strides past the end.
+pub fn sum<D, F>(dist: &D, h: F) -> Option<f64>
+where
+    D: Distribution + Discrete,
+    F: Fn(f64) -> f64,
+{
+    if let Some(values) = dist.support() {
+        let total: f64 =
+            values.iter().map(|x| h(*x as f64) * dist.pmf(*x)).sum();
+        return total.is_finite().then_some(total);
+    }
+    let (start, end) = (dist.quantile(TAIL), dist.quantile(1.0 - TAIL));
+    // odd, so that each block of stride values has a middle value to
+    // stand in for it
+    let stride = ((end - start) / TERMS).max(1) | 1;
+    let block = |lo: u64, width: u64| {
+        let x = lo + width / 2;
+        (x, width as f64 * dist.pmf(x))
+    };
+    // whether the rest of a tail no longer counts, where its terms fall
+    // at least as fast as from the last term to this one
+    let rest = |total: f64, size: f64, last: f64| {
+        size < last && total + size / (1.0 - size / last) == total
+    };
+    let mut total = (0.0, 0.0);
+    let mut last = f64::INFINITY;
+    for lo in (start..).step_by(stride as usize) {
+        // h(x) need not be defined where X cannot fall
+        let (x, p) = block(lo, stride);
+        if p == 0.0 {
+            match lo > end {
+                true => break,
+                false => continue,
+            }
+        }
+        let y = h(x as f64);
+        let size = p * y.abs().max(1.0);
+        if lo > end && rest(total.0, size, last) {
+            break;
+        }
+        if lo > end.saturating_add(TERMS * stride) {
+            return None;
+        }
+        add(&mut total, y * p);
+        last = size;
+    }
+    // the lower tail is finite, so it needs no limit
+    let (mut lo, mut last) = (start, f64::INFINITY);
+    while lo > 0 {
+        let width = stride.min(lo);
+        lo -= width;
+        let (x, p) = block(lo, width);
+        if p == 0.0 {
+            break;
+        }
+        let y = h(x as f64);
+        let size = p * y.abs().max(1.0);
+        if rest(total.0, size, last) {
+            break;
+        }
+        add(&mut total, y * p);
+        last = size;
+    }
+    let total = total.0 + total.1;
+    total.is_finite().then_some(total)
+}
+
+/// the smallest x with P(X <= x) >= 1/2, allowing for a cdf that falls
+/// just short of 1/2 by rounding, as cdf(0) does for Ber(0.5)
+pub fn median<D: Distribution + Discrete>(dist: &D) -> f64 {
+    dist.quantile(0.5 - 1e-12) as f64
+}
+
+/// the smallest of the most likely values
+pub fn mode<D: Distribution + Discrete>(dist: &D) -> f64 {
+    let values = match dist.support() {
+        Some(values) => values,
+        None => (dist.quantile(TAIL)..=dist.quantile(1.0 - TAIL)).collect(),
+    };
+    let (mut best, mut most) = (0, 0.0);
+    for x in values {
+        // ties may differ in the last few bits
+        if dist.pmf(x) > most * (1.0 + 1e-12) {
+            (best, most) = (x, dist.pmf(x));
+        }
+    }
+    best as f64
+}
+
+/// ∫ h(x) dx over [a, b], substituting x = a + (b - a)(3t² - 2t³) so
+/// that the steps are shortest at the ends, where the density may have
+/// a pole, as with Beta(0.5, 0.5)
+fn piece<F: Fn(f64) -> f64>(h: F, a: f64, b: f64) -> f64 {
+    let w = b - a;
+    let h =
+        |t: f64| h(a + w * t * t * (3.0 - 2.0 * t)) * 6.0 * w * t * (1.0 - t);
+    simpson(h, 0.0, 1.0, INTERVALS)
+}
+
+/// ∫ h(x) dx from b out to ∞, or to -∞ for negative w, substituting
+/// x = b + w s / (1 - s) so that the whole tail fits in s ∈ [0, 1)
+fn tail<F: Fn(f64) -> f64>(h: F, b: f64, w: f64) -> f64 {
+    let h = |s: f64| h(b + w * s / (1.0 - s)) * w.abs() / (1.0 - s).powi(2);
+    simpson(h, 0.0, 1.0 - 1e-9, INTERVALS)
+}
+
+/// The end of the support past b, in the direction of w, found by
+/// bisection if the density drops to 0 by b + w, and None otherwise
+fn edge<F: Fn(f64) -> f64>(pdf: F, b: f64, w: f64) -> Option<f64> {
+    let (mut inside, mut outside) = (b, b + w);
+    if pdf(outside) > 0.0 {
+        return None;
+    }
+    for _ in 0..200 {
+        let mid = inside + (outside - inside) / 2.0;
+        match pdf(mid) > 0.0 {
+            true => inside = mid,
+            false => outside = mid,
+        }
+    }
+    Some(inside)
+}
+
+/// Whether ∫ h(x) dx diverges at a, approached from a + d as d runs
+/// through successive decades. The part of the integral within a decade
+/// of a is about d h(a + d), which has to keep shrinking, by more than
+/// rounding, for the integral to converge.
+fn diverges<F, I>(h: F, a: f64, ds: I) -> bool
+where
+    F: Fn(f64) -> f64,
+    I: Iterator<Item = f64>,
+{
+    let parts: Vec<_> = ds.map(|d| (d * h(a + d)).abs()).collect();
+    parts.windows(2).any(|w| w[1].is_infinite() || w[1] > 0.9 * w[0])
+}
+
+/// ∫ h(x) f(x) dx, where f is the density. Integrates between the
+/// quantiles at 10⁻¹², 10⁻¹¹, ..., 0.1, 0.2, ..., 0.9, ..., 1 - 10⁻¹²,
+/// so that the steps are short where the density is concentrated and
+/// long out in heavy tails, and then on out to the edges of the support.
+/// None if the integral diverges in a tail or at an edge of the support,
+/// as ∫ x f(x) dx does for the Cauchy distribution.
+pub fn integral<D, F>(dist: &D, h: F) -> Option<f64>
+where
+    D: Continuous,
+    F: Fn(f64) -> f64,
+{
+    let tail_probs = (1..=12).map(|k| 10f64.powi(-k));
+    let mut probs: Vec<_> = tail_probs.clone().rev().collect();
+    probs.extend((2..=8).map(|k| k as f64 / 10.0));
+    probs.extend(tail_probs.map(|p| 1.0 - p));
+    let points: Vec<_> = probs.iter().map(|p| dist.inv_cdf(*p)).collect();
+    let (lo, hi) = (points[0], points[points.len() - 1]);
+
+    // outside the support h(x) need not be defined
+    let product = |x: f64| {
+        let d = dist.pdf(x);
+        match d > 0.0 && d.is_finite() {
+            true => h(x) * d,
+            false => 0.0,
+        }
+    };
+    // single points where h(x) or the density has a pole carry no weight
+    let weighted = |x: f64| match product(x) {
+        v if v.is_infinite() => 0.0,
+        v => v,
+    };
+    let body: f64 = points
+        .windows(2)
+        .filter(|w| w[0] < w[1])
+        .map(|w| piece(weighted, w[0], w[1]))
+        .sum();
+    // steps of d, d/10, d/100, ... in towards an edge of the support, or
+    // of d, 10d, 100d, ... out along a tail
+    let decades = |d: f64, r: f64| (0..=20).map(move |k| d * r.powi(k));
+    let end = |b: f64, w: f64| match edge(|x| dist.pdf(x), b, w) {
+        Some(e) => match diverges(product, e, decades(b - e, 0.1)) {
+            true => None,
+            false => Some(piece(weighted, e.min(b), e.max(b))),
+        },
+        None => match diverges(product, b, decades(w, 10.0)) {
+            true => None,
+            false => Some(tail(weighted, b, w)),
+        },
+    };
+    Some(body + end(lo, lo - hi)? + end(hi, hi - lo)?)
+}
+
+#[test]
+fn test() -> crate::utils::Result<()> {
+    use crate::distributions::{Binomial, Hypergeometric};
+    use crate::distributions::{NegativeBinomial, Poisson};
+
+    // the closed forms of the mode, skewness and excess kurtosis agree
+    // with summing around the mean
+    fn check<D: Distribution + Discrete>(dist: D) {
+        let (m, v) = (dist.mean().unwrap(), dist.variance().unwrap());
+        let central = |k| sum(&dist, |x| (x - m).powi(k)).unwrap();
+        float_eq!(dist.mode().unwrap(), mode(&dist));
+        float_eq!(dist.skewness().unwrap(), central(3) / v.powf(1.5));
+        float_eq!(dist.excess_kurtosis().unwrap(), central(4) / v / v - 3.0);
+    }
+    check(Binomial::new(10, 0.3)?);
+    check(Poisson::new(7.3)?);
+    check(NegativeBinomial::new(3, 0.25)?);
+    check(Hypergeometric::new(50, 10, 12)?);
+    // N < 4, where the closed forms divide by 0
+    check(Hypergeometric::new(3, 1, 1)?);
+    Ok(())
+}
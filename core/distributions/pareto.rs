@@ -0,0 +1,96 @@
+use crate::distributions::{build, Continuous, Distribution, Pareto};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::statistics::Distribution as X;
+
+impl Pareto {
+    pub fn new(m: f64, a: f64) -> Result<Pareto> {
+        let core = build(SR::Pareto::new(m, a))?;
+        Ok(Pareto { m, a, core })
+    }
+}
+
+impl Distribution for Pareto {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ Pa({m}, {a})", m = self.m, a = self.a)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(self.m * 2f64.powf(1.0 / self.a))
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(self.m)
+    }
+    /// ln(m / a) + 1 / a + 1, as statrs has the sign flipped
+    fn entropy(&self) -> Option<f64> {
+        Some((self.m / self.a).ln() + 1.0 / self.a + 1.0)
+    }
+    /// only exists for a > 3: 2(1 + a)/(a - 3) √{(a - 2)/a}
+    fn skewness(&self) -> Option<f64> {
+        let a = self.a;
+        match a > 3.0 {
+            true => Some(2.0 * (1.0 + a) / (a - 3.0) * ((a - 2.0) / a).sqrt()),
+            false => None,
+        }
+    }
+    /// only exists for a > 4: 6(a³ + a² - 6a - 2) / {a(a - 3)(a - 4)}
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let a = self.a;
+        match a > 4.0 {
+            true => {
+                let top = a.powi(3) + a * a - 6.0 * a - 2.0;
+                Some(6.0 * top / (a * (a - 3.0) * (a - 4.0)))
+            }
+            false => None,
+        }
+    }
+    /// only exists for k < a: a m^k / (a - k)
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let (k, a) = (k as f64, self.a);
+        match k < a {
+            true => Some(a * self.m.powf(k) / (a - k)),
+            false => None,
+        }
+    }
+}
+
+impl Continuous for Pareto {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// xₘ (1 - p)^(-1/α)
+    fn inv_cdf(&self, x: f64) -> f64 {
+        self.m * (1.0 - x).powf(-1.0 / self.a)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ Pa(m, a), then
+    // * P(X > x) = (m/x)^a for x >= m
+    // * E(X) = am/(a - 1) for a > 1
+    let dist = Pareto::new(1.0, 3.0)?;
+    float_eq!(dist.mean().unwrap(), 1.5);
+    float_eq!(dist.variance().unwrap(), 0.75);
+    float_eq!(dist.cdf(2.0), 0.875);
+    float_eq!(dist.inv_cdf(0.875), 2);
+    assert_eq!(Pareto::new(1.0, 1.0)?.mean(), None);
+    // E(X^k) only exists for k < a, and the entropy is ln(m/a) + 1/a + 1
+    assert_eq!(dist.raw_moment(3), None);
+    assert_eq!(dist.skewness(), None);
+    float_eq!(Pareto::new(1.0, 5.0)?.entropy().unwrap(), -0.4094379124);
+    assert!(Pareto::new(0.0, 1.0).is_err());
+    Ok(())
+}
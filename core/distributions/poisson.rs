@@ -1,8 +1,10 @@
-use crate::distributions::{build, Discrete, Distribution, Poisson};
+use crate::distributions::{build, peak, Discrete, Distribution, Poisson};
+use crate::math::{deviance, stirling_error};
 use crate::utils::Result;
 use statrs::distribution as SR;
-use statrs::distribution::{Discrete as Y, DiscreteCDF};
+use statrs::distribution::DiscreteCDF;
 use statrs::statistics::Distribution as X;
+use std::f64::consts::TAU;
 
 impl Poisson {
     pub fn new(l: f64) -> Result<Poisson> {
@@ -20,15 +22,39 @@ impl Distribution for Poisson {
     fn title(&self) -> String {
         format!("X ~ Poisson({l})", l = self.l)
     }
+    /// the smallest of the most likely values, ⌈l⌉ - 1 or 0
+    fn mode(&self) -> Option<f64> {
+        Some(peak(self.l))
+    }
+    /// 1/√l
+    fn skewness(&self) -> Option<f64> {
+        Some(1.0 / self.l.sqrt())
+    }
+    /// 1/l
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(1.0 / self.l)
+    }
+    by_summing!(median, entropy);
 }
 
 impl Discrete for Poisson {
+    /// by Loader's saddle point expansion, which keeps full precision for
+    /// large l, where the logarithms statrs takes do not
     fn pmf(&self, x: u64) -> f64 {
-        self.core.pmf(x)
+        match x {
+            0 => (-self.l).exp(),
+            _ => {
+                let lc = -stirling_error(x) - deviance(x as f64, self.l);
+                lc.exp() / (TAU * x as f64).sqrt()
+            }
+        }
     }
     fn cdf(&self, x: u64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: u64) -> f64 {
+        self.core.sf(x)
+    }
 }
 
 #[test]
@@ -42,5 +68,11 @@ fn test() -> Result<()> {
     float_eq!(dist.pmf(0), 0.04978706836);
     float_eq!(dist.cdf(3), 0.64723188878);
     float_eq!(Poisson::new(8.0)?.pmf(6), 0.12213821545);
+    // skewness 1/√l, excess kurtosis 1/l, mode ⌊l⌋ (or both l − 1 and l)
+    float_eq!(dist.skewness().unwrap(), 0.5773502692);
+    float_eq!(dist.excess_kurtosis().unwrap(), 1.0 / 3.0);
+    float_eq!(dist.mode().unwrap(), 2);
+    float_eq!(Poisson::new(3.5)?.mode().unwrap(), 3);
+    float_eq!(dist.median().unwrap(), 3);
     Ok(())
 }
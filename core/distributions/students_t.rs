@@ -6,7 +6,13 @@ use statrs::statistics::Distribution as X;
 
 impl StudentsT {
     pub fn new(freedom: u64) -> Result<StudentsT> {
-        let core = build(SR::StudentsT::new(0.0, 1.0, freedom as f64))?;
+        StudentsT::with_freedom(freedom as f64)
+    }
+
+    /// allows non-integer degrees of freedom, such as those
+    /// obtained from the Welch–Satterthwaite equation
+    pub fn with_freedom(freedom: f64) -> Result<StudentsT> {
+        let core = build(SR::StudentsT::new(0.0, 1.0, freedom))?;
         Ok(StudentsT { freedom, core })
     }
 }
@@ -21,6 +27,43 @@ impl Distribution for StudentsT {
     fn title(&self) -> String {
         format!("X ~ t({f})", f = self.freedom)
     }
+    fn median(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    /// only exists for n > 3
+    fn skewness(&self) -> Option<f64> {
+        match self.freedom > 3.0 {
+            true => Some(0.0),
+            false => None,
+        }
+    }
+    /// only exists for n > 4: 6/(n - 4)
+    fn excess_kurtosis(&self) -> Option<f64> {
+        match self.freedom > 4.0 {
+            true => Some(6.0 / (self.freedom - 4.0)),
+            false => None,
+        }
+    }
+    /// only exists for k < n: 0 for odd k, and otherwise the product
+    /// of n (2i - 1) / (n - 2i) for i = 1, ..., k/2
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let n = self.freedom;
+        match (k as f64) < n {
+            true if k % 2 == 1 => Some(0.0),
+            true => Some(
+                (1..=k / 2)
+                    .map(|i| n * (2 * i - 1) as f64 / (n - 2.0 * i as f64))
+                    .product(),
+            ),
+            false => None,
+        }
+    }
 }
 
 impl Continuous for StudentsT {
@@ -30,6 +73,9 @@ impl Continuous for StudentsT {
     fn cdf(&self, x: f64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
     fn inv_cdf(&self, x: f64) -> f64 {
         self.core.inverse_cdf(x)
     }
@@ -49,7 +95,7 @@ fn test() -> Result<()> {
         };
     }
 
-    assert_eq!(StudentsT::new(0).is_err(), true);
+    assert!(StudentsT::new(0).is_err());
     ttest!(1, None, None);
     ttest!(2, Some(0.0), None);
     ttest!(3, Some(0.0), Some(3.0));
@@ -0,0 +1,80 @@
+use crate::distributions::{Discrete, Distribution, Tabulated};
+use crate::utils::{err, Result};
+
+impl Tabulated {
+    /// a distribution over the given (value, probability) pairs, whose
+    /// values must be non-negative integers. Pairs may come in any order,
+    /// and the probabilities of a repeated value are added together.
+    /// Probabilities are scaled to sum to exactly 1, as files only need
+    /// them to add up to 1 to within rounding.
+    pub fn new(name: &str, pmf: &[(f64, f64)]) -> Result<Tabulated> {
+        if pmf.iter().any(|(v, _)| *v < 0.0 || v.fract() != 0.0) {
+            return err("Values must be non-negative integers.");
+        }
+        let total: f64 = pmf.iter().map(|v| v.1).sum();
+        let mut sorted: Vec<_> =
+            pmf.iter().map(|(v, p)| (*v as u64, p / total)).collect();
+        sorted.sort_by_key(|v| v.0);
+        let mut pmf: Vec<(u64, f64)> = vec![];
+        for (v, p) in sorted {
+            match pmf.last_mut() {
+                Some(last) if last.0 == v => last.1 += p,
+                _ => pmf.push((v, p)),
+            }
+        }
+        Ok(Tabulated { name: name.to_string(), pmf })
+    }
+}
+
+impl Distribution for Tabulated {
+    fn mean(&self) -> Option<f64> {
+        Some(self.pmf.iter().map(|(v, p)| *v as f64 * p).sum())
+    }
+    fn variance(&self) -> Option<f64> {
+        let m = self.mean()?;
+        Some(self.pmf.iter().map(|(v, p)| (*v as f64 - m).powi(2) * p).sum())
+    }
+    fn title(&self) -> String {
+        format!("X ~ {}", self.name)
+    }
+    by_summing!(median, mode, entropy);
+    by_summing!(central_moment, skewness, excess_kurtosis);
+}
+
+impl Discrete for Tabulated {
+    fn pmf(&self, x: u64) -> f64 {
+        self.pmf.iter().find(|v| v.0 == x).map_or(0.0, |v| v.1)
+    }
+    fn cdf(&self, x: u64) -> f64 {
+        self.pmf.iter().take_while(|v| v.0 <= x).map(|v| v.1).sum()
+    }
+    fn sf(&self, x: u64) -> f64 {
+        self.pmf.iter().skip_while(|v| v.0 <= x).map(|v| v.1).sum()
+    }
+    fn support(&self) -> Option<Vec<u64>> {
+        Some(self.pmf.iter().map(|v| v.0).collect())
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if P(X = x) is given for each value x, then
+    // * E(X) = Σ x P(X = x)
+    // * var(X) = Σ (x - E(X))² P(X = x)
+    let dist = Tabulated::new("X", &[(1.0, 0.2), (2.0, 0.3), (4.0, 0.5)])?;
+    float_eq!(dist.mean().unwrap(), 2.8);
+    float_eq!(dist.variance().unwrap(), 1.56);
+    float_eq!(dist.pmf(2), 0.3);
+    float_eq!(dist.pmf(3), 0);
+    float_eq!(dist.cdf(3), 0.5);
+    float_eq!(dist.cdf(0), 0);
+    assert!(Tabulated::new("X", &[(-1.0, 0.5), (1.0, 0.5)]).is_err());
+    assert!(Tabulated::new("X", &[(0.5, 1.0)]).is_err());
+
+    // unsorted pairs and repeated values give the same distribution
+    let dist = Tabulated::new("X", &[(4.0, 0.5), (1.0, 0.2), (4.0, 0.3)])?;
+    assert_eq!(dist.pmf, vec![(1, 0.2), (4, 0.8)]);
+    float_eq!(dist.cdf(3), 0.2);
+    float_eq!(dist.sf(1), 0.8);
+    Ok(())
+}
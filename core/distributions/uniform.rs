@@ -1,4 +1,4 @@
-use crate::distributions::{build, Distribution, Continuous, Uniform};
+use crate::distributions::{build, Continuous, Distribution, Uniform};
 use crate::utils::Result;
 use statrs::distribution as SR;
 use statrs::distribution::{Continuous as Y, ContinuousCDF};
@@ -20,6 +20,27 @@ impl Distribution for Uniform {
     fn title(&self) -> String {
         format!("X ~ U({a}, {b})", a = self.min, b = self.max)
     }
+    fn median(&self) -> Option<f64> {
+        Some((self.min + self.max) / 2.0)
+    }
+    /// every value in [a, b] is equally likely
+    fn mode(&self) -> Option<f64> {
+        None
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    fn excess_kurtosis(&self) -> Option<f64> {
+        Some(-1.2)
+    }
+    /// (b^(k+1) - a^(k+1)) / ((k + 1)(b - a))
+    fn raw_moment(&self, k: i32) -> Option<f64> {
+        let (a, b) = (self.min, self.max);
+        Some((b.powi(k + 1) - a.powi(k + 1)) / ((k + 1) as f64 * (b - a)))
+    }
 }
 
 impl Continuous for Uniform {
@@ -29,8 +50,12 @@ impl Continuous for Uniform {
     fn cdf(&self, x: f64) -> f64 {
         self.core.cdf(x)
     }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// a + p(b - a)
     fn inv_cdf(&self, x: f64) -> f64 {
-        self.core.inverse_cdf(x)
+        self.min + x * (self.max - self.min)
     }
 }
 
@@ -46,5 +71,6 @@ fn test() -> Result<()> {
     float_eq!(dist.pdf(3.0), 0.5);
     float_eq!(dist.pdf(1.99), 0);
     float_eq!(dist.pdf(4.01), 0);
+    float_eq!(dist.inv_cdf(0.25), 2.5);
     Ok(())
 }
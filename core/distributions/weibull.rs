@@ -0,0 +1,86 @@
+use crate::distributions::{build, Continuous, Distribution, Weibull};
+use crate::utils::Result;
+use statrs::distribution as SR;
+use statrs::distribution::{Continuous as Y, ContinuousCDF};
+use statrs::function::gamma::gamma;
+use statrs::statistics::Distribution as X;
+use std::f64::consts::LN_2;
+
+impl Weibull {
+    pub fn new(k: f64, l: f64) -> Result<Weibull> {
+        let core = build(SR::Weibull::new(k, l))?;
+        Ok(Weibull { k, l, core })
+    }
+}
+
+impl Distribution for Weibull {
+    fn mean(&self) -> Option<f64> {
+        self.core.mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        self.core.variance()
+    }
+    fn title(&self) -> String {
+        format!("X ~ W({k}, {l})", k = self.k, l = self.l)
+    }
+    fn median(&self) -> Option<f64> {
+        Some(self.l * LN_2.powf(1.0 / self.k))
+    }
+    /// for k < 1, the density has a pole at 0
+    fn mode(&self) -> Option<f64> {
+        match self.k > 1.0 {
+            true => Some(self.l * ((self.k - 1.0) / self.k).powf(1.0 / self.k)),
+            false => Some(0.0),
+        }
+    }
+    fn entropy(&self) -> Option<f64> {
+        self.core.entropy()
+    }
+    /// (g₃ - 3g₁g₂ + 2g₁³) / (g₂ - g₁²)^(3/2), where gᵢ = Γ(1 + i/k)
+    fn skewness(&self) -> Option<f64> {
+        let g = |i: f64| gamma(1.0 + i / self.k);
+        let (g1, g2, g3) = (g(1.0), g(2.0), g(3.0));
+        let top = g3 - 3.0 * g1 * g2 + 2.0 * g1.powi(3);
+        Some(top / (g2 - g1 * g1).powf(1.5))
+    }
+    /// (g₄ - 4g₁g₃ + 6g₁²g₂ - 3g₁⁴) / (g₂ - g₁²)² - 3, where gᵢ = Γ(1 + i/k)
+    fn excess_kurtosis(&self) -> Option<f64> {
+        let g = |i: f64| gamma(1.0 + i / self.k);
+        let (g1, g2, g3, g4) = (g(1.0), g(2.0), g(3.0), g(4.0));
+        let top = g4 - 4.0 * g1 * g3 + 6.0 * g1 * g1 * g2 - 3.0 * g1.powi(4);
+        Some(top / (g2 - g1 * g1).powi(2) - 3.0)
+    }
+    /// l^j Γ(1 + j/k) for the jth moment
+    fn raw_moment(&self, j: i32) -> Option<f64> {
+        Some(self.l.powi(j) * gamma(1.0 + j as f64 / self.k))
+    }
+}
+
+impl Continuous for Weibull {
+    fn pdf(&self, x: f64) -> f64 {
+        self.core.pdf(x)
+    }
+    fn cdf(&self, x: f64) -> f64 {
+        self.core.cdf(x)
+    }
+    fn sf(&self, x: f64) -> f64 {
+        self.core.sf(x)
+    }
+    /// λ {-ln(1 - p)}^(1/k)
+    fn inv_cdf(&self, x: f64) -> f64 {
+        self.l * (-(-x).ln_1p()).powf(1.0 / self.k)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // if X ~ W(k, l), then
+    // * P(X <= x) = 1 - exp(-(x/l)^k)
+    // * E(X) = l Γ(1 + 1/k)
+    let dist = Weibull::new(1.5, 2.0)?;
+    float_eq!(dist.mean().unwrap(), 1.8054905859);
+    float_eq!(dist.cdf(1.0), 0.2978114987);
+    float_eq!(dist.inv_cdf(0.2978114987), 1);
+    assert!(Weibull::new(0.0, 1.0).is_err());
+    Ok(())
+}
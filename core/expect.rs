@@ -0,0 +1,119 @@
+//! Expectations of functions of a random variable, where the function
+//! g is given as an expression in x, such as "x^2" or "exp(x)".
+use crate::display::LineList;
+use crate::distributions::numerical::{integral, sum};
+use crate::distributions::{Continuous, Discrete, Distribution};
+use crate::utils::{err, Result, ResultOps};
+use std::cell::Cell;
+
+/// the function g(x) described by the expression
+fn function(g: &str) -> Result<impl Fn(f64) -> f64> {
+    let expr: meval::Expr = g.parse().serr("Invalid expression.")?;
+    expr.bind("x").serr("The expression may only use the variable x.")
+}
+
+/// E[g(X)] and Var[g(X)] = E[{g(X) - E[g(X)]}²], from `total`, which
+/// takes E[h(X)] for a function h and is None where the sum or integral
+/// diverges. The variance is summed around the mean, as E[g(X)²] - E[g(X)]²
+/// cancels badly for a large mean, and is left out if it diverges, as for
+/// X ~ t(2). Both are divided by the probability the sum or integral
+/// covers, so that what it misses cancels, and a constant has a variance
+/// of exactly 0.
+fn lines<T>(g: &str, total: T) -> Result<LineList>
+where
+    T: Fn(&dyn Fn(f64) -> f64) -> Option<f64>,
+{
+    let f = function(g)?;
+    // g(x) is NaN where it is undefined, such as √x for x < 0
+    let undefined = Cell::new(false);
+    let f = |x: f64| {
+        let y = f(x);
+        undefined.set(undefined.get() || y.is_nan());
+        y
+    };
+    let mass = total(&|_| 1.0).unwrap_or(1.0);
+    let first = total(&f);
+    if undefined.get() {
+        return err(&format!("{g} is not defined everywhere X may fall."));
+    }
+    let Some(first) = first.map(|v| v / mass) else {
+        return err(&format!("E[{g}] does not exist."));
+    };
+    let mut list = LineList::new();
+    list.push(&format!("E[{g}]"), first);
+    if let Some(second) = total(&|x| (f(x) - first).powi(2)) {
+        list.push(&format!("Var[{g}]"), (second / mass).max(0.0));
+    }
+    Ok(list)
+}
+
+/// E[g(X)] and Var[g(X)] for a function g of X, given as an
+/// expression in x
+pub trait Expect<T> {
+    fn expect(&self, g: &str) -> Result<LineList>;
+}
+
+impl<D: Distribution + Discrete> Expect<u64> for D {
+    fn expect(&self, g: &str) -> Result<LineList> {
+        lines(g, |h| sum(self, h))
+    }
+}
+
+impl<D: Distribution + Continuous> Expect<f64> for D {
+    fn expect(&self, g: &str) -> Result<LineList> {
+        lines(g, |h| integral(self, h))
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    use crate::distributions::Uniform;
+    use crate::distributions::{Binomial, Cauchy, Exponential, Geometric};
+    use crate::distributions::{Normal, Poisson, StudentsT, Tabulated};
+    let value = |list: LineList, i: usize| list.list[i].val.unwrap();
+
+    let dist = Tabulated::new("X", &[(1.0, 0.2), (2.0, 0.3), (4.0, 0.5)])?;
+    float_eq!(value(dist.expect("x")?, 0), 2.8);
+    float_eq!(value(dist.expect("x")?, 1), 1.56);
+    float_eq!(value(dist.expect("x^2")?, 0), 9.4);
+    // E[X²] = var(X) + E(X)² = np(1 - p) + (np)²
+    let dist = Binomial::new(10, 0.3)?;
+    float_eq!(value(dist.expect("x^2")?, 0), 11.1);
+    assert!(dist.expect("x + y").is_err());
+    assert!(dist.expect("x +").is_err());
+
+    let dist = Normal::new(1.0, 2.0)?;
+    float_eq!(value(dist.expect("x")?, 0), 1.0);
+    float_eq!(value(dist.expect("x")?, 1), 4.0);
+    // E[e^X] = exp(m + s²/2), the mean of LogN(m, s²)
+    float_eq!(value(dist.expect("exp(x)")?, 0), 3f64.exp());
+    let dist = Exponential::new(2.0)?;
+    float_eq!(value(dist.expect("x^2")?, 0), 0.5);
+
+    // E[X] does not exist for X ~ C(0, 1), as x f(x) ~ 1/(πx) in the
+    // tails, nor E[1/X] for X ~ U(0, 1), with its pole at 0
+    assert!(Cauchy::new(0.0, 1.0)?.expect("x").is_err());
+    assert!(Uniform::new(0.0, 1.0)?.expect("1/x").is_err());
+    // E[2^X] = Σ 2^x (1/2)^x diverges for X ~ G(1/2)
+    assert!(Geometric::new(0.5)?.expect("2^x").is_err());
+    // for X ~ t(2), E[X] = 0 but Var[X] is infinite
+    let list = StudentsT::new(2)?.expect("x")?;
+    assert_eq!(list.list.len(), 1);
+    float_eq!(value(list, 0), 0.0);
+    // √x is not defined for x < 0
+    assert!(Normal::new(0.0, 1.0)?.expect("sqrt(x)").is_err());
+
+    // Var[X] is summed around a large mean, where E[X²] - E[X]² cancels
+    let list = Binomial::new(1_000_000_000, 0.5)?.expect("x")?;
+    float_eq!(list.list[0].val.unwrap() / 5e8, 1.0);
+    float_eq!(list.list[1].val.unwrap() / 2.5e8, 1.0);
+    let list = Poisson::new(1e6)?.expect("x")?;
+    float_eq!(list.list[0].val.unwrap() / 1e6, 1.0);
+    float_eq!(list.list[1].val.unwrap() / 1e6, 1.0);
+    // summed in strides across a billion values
+    let list = Geometric::new(1e-9)?.expect("x")?;
+    float_eq!(list.list[0].val.unwrap() / 1e9, 1.0);
+    // the integral misses some of the tails, but a constant has no spread
+    float_eq!(value(Cauchy::new(0.0, 1.0)?.expect("1")?, 1), 0.0);
+    Ok(())
+}
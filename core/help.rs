@@ -7,4 +7,117 @@ File data can be one of two formats:
 2. <value> <probability> on each line
    │ 10 0.2
    │ 20 0.3
-   │ 30 0.5";
+   │ 30 0.5
+With probabilities, statistics describe the weighted data, and
+sample-only statistics such as the sample variance and the standard
+error of the mean are omitted.";
+
+pub const PMF: &str = "Discrete distribution from a file
+File data is a <value> <probability> pair on each line
+   │ 0 0.2
+   │ 1 0.5
+   │ 3 0.3
+Values must be non-negative integers, in any order; the probabilities
+of a repeated value are added. A file of values alone, one per line,
+gives each line an equal probability. For other values, such as a -1/+1
+payoff, use the data subcommand, which summarizes any such file.
+With --expect, E[g(X)] and Var[g(X)] are found for an expression g in
+x, such as x^2 or exp(x).";
+
+pub const BOX: &str = "Five-number summary, outliers and box plot
+Outliers lie beyond Tukey's fences, 1.5 interquartile ranges below
+the 1st quartile or above the 3rd quartile. The whiskers reach the
+most extreme values within the fences.
+File data is in the same format as for the data subcommand.";
+
+pub const HIST: &str = "Histogram of data from a file
+Bins have equal widths, starting from the smallest value. Each bin
+includes its left edge, and the last bin also includes the largest
+value. Unless --bins or --width is given, the bin width is chosen by
+a rule (--rule):
+  sturges  ⌈log₂ n⌉ + 1 bins
+  scott    width 3.49 s / ∛n
+  fd       width 2 IQR / ∛n (Freedman–Diaconis)
+File data is in the same format as for the data subcommand. For
+value-probability pairs, bins show total probability instead of counts.";
+
+pub const REGRESS: &str = "Simple linear regression, fitting y = a + bx
+File data is one <x> <y> pair on each line
+   │ 1 2.0
+   │ 2 4.1
+   │ 3 5.9
+Coefficients are tested against H0: coefficient = 0, using t(n - 2).
+At each given x, the confidence interval (CI) is for the mean of y,
+and the prediction interval (PI) is for a single new observation.";
+
+pub const CORR: &str = "Correlation of x y pairs from a file
+File data is one <x> <y> pair on each line, as for diff and regress.
+Each coefficient is tested against H0: no correlation.
+  Pearson r   t-test with n - 2 degrees of freedom, and a confidence
+              interval from Fisher's transformation atanh(r)
+  Spearman ρ  Pearson r of the ranks, with the same t-test
+  Kendall τ   tau-b, with a normal approximation corrected for ties";
+
+pub const ANOVA: &str = "One-way analysis of variance
+Tests H0: all population means are equal, assuming normal populations
+with equal variances. Each file holds one sample, one number per line.
+With --grouped, a single file holds every sample, with one
+<group> <value> pair on each line
+   │ a 6
+   │ b 8
+   │ a 4
+With --post, each pair of means is compared, with p-values and
+confidence intervals adjusted for the number of comparisons.";
+
+pub const SIGN: &str = "Sign test of the median of data from a file
+Tests H0: median = MEDIAN, using one number per line, or the
+differences x - y of <x> <y> pairs with --paired. Values equal to the
+median are dropped, and the number of values above it is tested
+exactly against Binomial(n, 0.5).";
+
+pub const WILCOXON: &str = "Wilcoxon signed-rank test of data from a file
+Tests H0: the distribution is symmetric about MEDIAN, using one number
+per line, or the differences x - y of <x> <y> pairs with --paired.
+Values equal to the median are dropped. W+ is the sum of the ranks of
+|x - MEDIAN| over the values above the median.
+P-values are exact for up to 50 values without ties, and otherwise use
+a normal approximation, with the variance corrected for ties.";
+
+pub const MANN_WHITNEY: &str = "Mann-Whitney U test of two independent samples
+Tests H0: both samples come from the same distribution, with one
+number per line in each file. U counts the pairs in which the value
+from the first sample is larger, with ties counting one half.
+P-values are exact for up to 50 values in all without ties, and
+otherwise use a normal approximation, with the variance corrected for
+ties.";
+
+pub const PROP: &str = "Inference for one or two population proportions
+Each sample is given as a count of wins out of a number of trials.
+  one  exact binomial test of H0: p = P, and the normal approximation
+       with a continuity correction of half a win
+  two  z-test of H0: p1 = p2 using the pooled proportion, with a
+       confidence interval for p1 - p2
+  ci   Wald, Wilson score and Clopper-Pearson (exact) intervals for p";
+
+pub const GOF: &str = "Chi-squared goodness-of-fit test on data from a file
+File data can be one of two formats:
+1. <observed> on each line, for equally likely categories
+   │ 10
+   │ 20
+   │ 30
+2. <observed> <expected> on each line, where expected values
+   are either counts or probabilities
+   │ 10 0.2
+   │ 20 0.3
+   │ 30 0.5
+With --fit, each line is <value> <observed> instead, and the
+distribution is fitted using the sample mean. The last value
+represents itself and all larger values.
+   │ 0 12
+   │ 1 20
+   │ 2 8";
+
+pub const CHISQ_INDEP: &str = "Chi-squared test of independence
+File data is a table of observed counts, one row per line
+   │ 10 20 30
+   │ 15 25 20";
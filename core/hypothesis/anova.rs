@@ -0,0 +1,266 @@
+use crate::confidence::label;
+use crate::display::LineList;
+use crate::distributions::{Continuous, FisherSnedecor, StudentsT};
+use crate::hypothesis::p_value;
+use crate::math::{no_variance, simpson};
+use crate::utils::{err, Result};
+use crate::{Area, PostHoc};
+use statrs::function::erf::erfc;
+use statrs::function::gamma::ln_gamma;
+
+/// a sample, summarized
+struct Group {
+    name: String,
+    n: f64,
+    mean: f64,
+    var: f64,
+}
+
+/// One-way analysis of variance of H0: all population means are
+/// equal, using samples from normal populations with equal variances
+pub struct Anova {
+    groups: Vec<Group>,
+}
+
+/// standard normal c.d.f. and p.d.f.
+fn phi(z: f64) -> f64 {
+    erfc(-z / std::f64::consts::SQRT_2) / 2.0
+}
+fn density(z: f64) -> f64 {
+    (-z * z / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// c.d.f. of the range of k standard normal variables, divided by the
+/// same rule's value at w = ∞ so that its error cancels in the tail
+fn normal_range(w: f64, k: f64) -> f64 {
+    let f = |z: f64| density(z) * (phi(z) - phi(z - w)).powf(k - 1.0);
+    let whole = |z: f64| density(z) * phi(z).powf(k - 1.0);
+    (simpson(f, -8.0, 8.0, 100) / simpson(whole, -8.0, 8.0, 100))
+        .clamp(0.0, 1.0)
+}
+
+/// c.d.f. of the studentized range distribution of k means with
+/// `df` degrees of freedom, integrating over the distribution of
+/// s = sqrt(χ²(df) / df)
+fn ptukey(q: f64, k: f64, df: f64) -> f64 {
+    if q <= 0.0 {
+        return 0.0;
+    }
+    if df > 5000.0 {
+        return normal_range(q, k);
+    }
+    let half = df / 2.0;
+    let ln_c = half * df.ln() - ln_gamma(half) - (half - 1.0) * 2f64.ln();
+    // s^(df - 1) is 1 at s = 0 when df = 1, where 0 × ln 0 would be NaN
+    let s_density = |s: f64| {
+        let ln_s = if df > 1.0 { (df - 1.0) * s.ln() } else { 0.0 };
+        (ln_c + ln_s - df * s * s / 2.0).exp()
+    };
+    let f = |s: f64| s_density(s) * normal_range(q * s, k);
+    // s is concentrated around 1, with a spread of about 1 / sqrt(2 df).
+    // Dividing by the same rule over the density of s alone cancels its
+    // error, so that far apart means leave no tail at all.
+    let spread = 8.0 / (2.0 * df).sqrt();
+    let (a, b) = ((1.0 - spread).max(0.0), 1.0 + spread);
+    (simpson(f, a, b, 200) / simpson(s_density, a, b, 200)).clamp(0.0, 1.0)
+}
+
+/// quantile of the studentized range distribution, by bisection
+fn qtukey(p: f64, k: f64, df: f64) -> f64 {
+    let (mut lo, mut hi) = (0.0, 1.0);
+    while ptukey(hi, k, df) < p && hi < 1e3 {
+        hi *= 2.0;
+    }
+    for _ in 0..40 {
+        let mid = (lo + hi) / 2.0;
+        match ptukey(mid, k, df) < p {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+impl Anova {
+    /// named samples, each with at least one value
+    pub fn new(samples: &[(String, Vec<f64>)]) -> Result<Anova> {
+        if samples.len() < 2 {
+            return err("ANOVA needs at least 2 samples.");
+        }
+        let groups: Vec<_> = samples
+            .iter()
+            .map(|(name, v)| {
+                let n = v.len() as f64;
+                let mean = v.iter().sum::<f64>() / n;
+                let ss = v.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+                // as in Data, variance left only by rounding is 0
+                let ss = match no_variance(ss / n, ss / n + mean * mean) {
+                    true => 0.0,
+                    false => ss,
+                };
+                let var = if n > 1.0 { ss / (n - 1.0) } else { 0.0 };
+                Group { name: name.to_string(), n, mean, var }
+            })
+            .collect();
+        if groups.iter().any(|g| g.n == 0.0) {
+            return err("every sample needs at least one value.");
+        }
+        let anova = Anova { groups };
+        if anova.df_within() < 1.0 {
+            return err("ANOVA needs more values than samples.");
+        }
+        if anova.ss_within() == 0.0 {
+            return err("The samples have no variance within them.");
+        }
+        Ok(anova)
+    }
+
+    fn n(&self) -> f64 {
+        self.groups.iter().map(|g| g.n).sum()
+    }
+
+    fn k(&self) -> f64 {
+        self.groups.len() as f64
+    }
+
+    fn grand_mean(&self) -> f64 {
+        self.groups.iter().map(|g| g.n * g.mean).sum::<f64>() / self.n()
+    }
+
+    fn ss_between(&self) -> f64 {
+        let m = self.grand_mean();
+        self.groups.iter().map(|g| g.n * (g.mean - m).powi(2)).sum()
+    }
+
+    fn ss_within(&self) -> f64 {
+        self.groups.iter().map(|g| (g.n - 1.0) * g.var).sum()
+    }
+
+    fn df_between(&self) -> f64 {
+        self.k() - 1.0
+    }
+
+    fn df_within(&self) -> f64 {
+        self.n() - self.k()
+    }
+
+    fn ms_within(&self) -> f64 {
+        self.ss_within() / self.df_within()
+    }
+
+    pub fn f(&self) -> f64 {
+        (self.ss_between() / self.df_between()) / self.ms_within()
+    }
+
+    /// Tukey-Kramer or Bonferroni comparison of each pair of means,
+    /// with adjusted p-values and simultaneous confidence intervals
+    fn post_hoc(&self, post: PostHoc, alpha: f64) -> Result<LineList> {
+        let (k, df) = (self.k(), self.df_within());
+        let t = StudentsT::with_freedom(df)?;
+        let pairs = k * (k - 1.0) / 2.0;
+        let critical = match post {
+            PostHoc::Tukey => qtukey(1.0 - alpha, k, df) / 2f64.sqrt(),
+            PostHoc::Bonferroni => t.inv_cdf(1.0 - alpha / (2.0 * pairs)),
+        };
+        let label = label(alpha);
+        let (lower, upper) =
+            (format!("{label} lower bound"), format!("{label} upper bound"));
+        let mut list = LineList::new();
+        for (i, a) in self.groups.iter().enumerate() {
+            for b in &self.groups[i + 1..] {
+                let diff = a.mean - b.mean;
+                let se = (self.ms_within() * (1.0 / a.n + 1.0 / b.n)).sqrt();
+                list.header(&format!("{} - {}", a.name, b.name));
+                list.push("difference of means", diff);
+                list.push("standard error", se);
+                match post {
+                    PostHoc::Tukey => {
+                        let q = diff.abs() / se * 2f64.sqrt();
+                        list.push("q statistic", q);
+                        list.push("adjusted p-value", 1.0 - ptukey(q, k, df));
+                    }
+                    PostHoc::Bonferroni => {
+                        let p = 2.0 * (1.0 - t.cdf((diff / se).abs()));
+                        list.push("t statistic", diff / se);
+                        list.push("adjusted p-value", (p * pairs).min(1.0));
+                    }
+                }
+                list.push(&lower, diff - critical * se);
+                list.push(&upper, diff + critical * se);
+            }
+        }
+        Ok(list)
+    }
+
+    pub fn export(
+        &self,
+        post: Option<PostHoc>,
+        alpha: f64,
+    ) -> Result<LineList> {
+        let dist = FisherSnedecor::new(
+            self.df_between() as u64,
+            self.df_within() as u64,
+        )?;
+        let mut list = LineList::new();
+        list.set_title("H0: all population means are equal");
+        for g in &self.groups {
+            list.header(&g.name);
+            list.push("sample size", g.n);
+            list.push("mean", g.mean);
+            list.push("sample variance", g.var);
+        }
+        let ss_total = self.ss_between() + self.ss_within();
+        let rows = [
+            ("between groups", self.ss_between(), self.df_between()),
+            ("within groups", self.ss_within(), self.df_within()),
+        ];
+        for (name, ss, df) in rows {
+            list.header(name);
+            list.push("sum of squares", ss);
+            list.push("degrees of freedom", df);
+            list.push("mean square", ss / df);
+        }
+        list.header("total");
+        list.push("sum of squares", ss_total);
+        list.push("degrees of freedom", self.n() - 1.0);
+        list.header("F-test");
+        list.push("F statistic", self.f());
+        list.append(&p_value(&dist, self.f(), Area::Right));
+        let Some(post) = post else {
+            return Ok(list);
+        };
+        list.header(match post {
+            PostHoc::Tukey => "Tukey HSD",
+            PostHoc::Bonferroni => "Bonferroni",
+        });
+        list.append(&self.post_hoc(post, alpha)?);
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    // critical values from tables of the studentized range
+    float_eq!(ptukey(3.877, 3.0, 10.0), 0.95, 4);
+    float_eq!(ptukey(2.772, 2.0, 1e6), 0.95, 4);
+    float_eq!(ptukey(17.97, 2.0, 1.0), 0.95, 4);
+    float_eq!(qtukey(0.95, 4.0, 20.0), 3.9583, 4);
+
+    let sample = |name: &str, v: &[f64]| (name.to_string(), v.to_vec());
+    let anova = Anova::new(&[
+        sample("a", &[6.0, 8.0, 4.0, 5.0, 3.0, 4.0]),
+        sample("b", &[8.0, 12.0, 9.0, 11.0, 6.0, 8.0]),
+        sample("c", &[13.0, 9.0, 11.0, 8.0, 7.0, 12.0]),
+    ])?;
+    float_eq!(anova.ss_between(), 84.0);
+    float_eq!(anova.ss_within(), 68.0);
+    float_eq!(anova.f(), 9.2647058824);
+    assert!(Anova::new(&[sample("a", &[1.0]), sample("b", &[2.0])]).is_err());
+    // their means are not exactly 0.1 and 0.7
+    let same = [sample("a", &[0.1; 3]), sample("b", &[0.7; 3])];
+    assert!(Anova::new(&same).is_err());
+    // far apart means, by the q statistic of Tukey HSD
+    assert_eq!(ptukey(76.0, 3.0, 87.0), 1.0);
+    float_eq!(1.0 - ptukey(30.5595956925, 3.0, 10.0), 2.7637e-9, 11);
+    Ok(())
+}
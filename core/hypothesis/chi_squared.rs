@@ -0,0 +1,212 @@
+use crate::display::LineList;
+use crate::distributions::{ChiSquared, Continuous, Discrete, Distribution};
+use crate::math::Round;
+use crate::utils::{err, Result};
+
+/// Pearson's chi-squared test statistic, computed from
+/// observed and expected counts of each category
+pub struct ChiSquaredTest {
+    title: String,
+    /// description of each category
+    labels: Vec<String>,
+    observed: Vec<f64>,
+    expected: Vec<f64>,
+    freedom: u64,
+}
+
+/// the sum of all elements of a list
+fn total(list: &[f64]) -> f64 {
+    list.iter().sum()
+}
+
+/// checks that a value is a non-negative integer
+fn count(v: f64) -> Result<u64> {
+    match v >= 0.0 && v.fract() == 0.0 {
+        true => Ok(v as u64),
+        false => err("Values must be non-negative integers."),
+    }
+}
+
+/// expected counts of each value in `values` if a total of `n`
+/// observations were drawn from `dist`. The first and last values
+/// absorb the probability of the lower and upper tails respectively.
+fn fitted(dist: &impl Discrete, values: &[u64], n: f64) -> Vec<f64> {
+    let last = values.len() - 1;
+    let p = |(i, v): (usize, &u64)| match (i, v) {
+        (_, v) if i == last => {
+            1.0 - v.checked_sub(1).map_or(0.0, |v| dist.cdf(v))
+        }
+        (0, v) => dist.cdf(*v),
+        (_, v) => dist.pmf(*v),
+    };
+    values.iter().enumerate().map(|v| n * p(v)).collect()
+}
+
+impl ChiSquaredTest {
+    /// goodness-of-fit test on rows of `<observed>` or
+    /// `<observed> <expected>`. Expected values can be either counts
+    /// or probabilities. When absent, all categories are equally likely.
+    pub fn goodness_of_fit(rows: &[Vec<f64>]) -> Result<ChiSquaredTest> {
+        let observed: Vec<_> = rows.iter().map(|v| v[0]).collect();
+        let n = total(&observed);
+        let expected = match rows.iter().all(|v| v.len() == 1) {
+            true => vec![n / rows.len() as f64; rows.len()],
+            false => {
+                if rows.iter().any(|v| v.len() != 2) {
+                    return err("Each line must be <observed> [expected].");
+                }
+                let expected: Vec<_> = rows.iter().map(|v| v[1]).collect();
+                let scale = match (total(&expected) - 1.0).abs() < 1e-10 {
+                    true => n,
+                    false => 1.0,
+                };
+                expected.iter().map(|v| v * scale).collect()
+            }
+        };
+        let labels = (1..=rows.len()).map(|i| format!("row {i}")).collect();
+        let title = "H0: data follows the expected counts".to_string();
+        ChiSquaredTest::new(title, labels, observed, expected, 0)
+    }
+
+    /// goodness-of-fit test of a discrete distribution that was fitted
+    /// using one parameter estimated from rows of `<value> <observed>`.
+    /// `build` creates the distribution from the sample mean.
+    pub fn fit<D, F>(rows: &[Vec<f64>], build: F) -> Result<ChiSquaredTest>
+    where
+        D: Distribution + Discrete,
+        F: Fn(f64) -> Result<D>,
+    {
+        if rows.iter().any(|v| v.len() != 2) {
+            return err("Each line must be <value> <observed>.");
+        }
+        let values =
+            rows.iter().map(|v| count(v[0])).collect::<Result<Vec<_>>>()?;
+        if values.windows(2).any(|v| v[0] >= v[1]) {
+            return err("Values must be in increasing order.");
+        }
+        let observed: Vec<_> = rows.iter().map(|v| v[1]).collect();
+        let n = total(&observed);
+        let mean = rows.iter().map(|v| v[0] * v[1]).sum::<f64>() / n;
+        // rounded so that the distribution's title stays readable
+        let dist = build(mean.roundn(10))?;
+        let expected = fitted(&dist, &values, n);
+        let last = values.len() - 1;
+        let labels = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| match i == last {
+                true => format!("{v}+"),
+                false => v.to_string(),
+            })
+            .collect();
+        let title = format!("H0: {}", dist.title());
+        ChiSquaredTest::new(title, labels, observed, expected, 1)
+    }
+
+    /// test of independence of the rows and columns of a contingency
+    /// table of observed counts
+    pub fn independence(rows: &[Vec<f64>]) -> Result<ChiSquaredTest> {
+        let (r, c) = (rows.len(), rows[0].len());
+        if rows.iter().any(|v| v.len() != c) {
+            return err("All rows must have the same number of columns.");
+        }
+        if r < 2 || c < 2 {
+            return err("The table must have at least 2 rows and 2 columns.");
+        }
+        let row_totals: Vec<_> = rows.iter().map(|v| total(v)).collect();
+        let col_totals: Vec<_> =
+            (0..c).map(|j| rows.iter().map(|v| v[j]).sum::<f64>()).collect();
+        let n = total(&row_totals);
+        let (mut labels, mut observed, mut expected) = (vec![], vec![], vec![]);
+        for i in 0..r {
+            for j in 0..c {
+                labels.push(format!("({}, {})", i + 1, j + 1));
+                observed.push(rows[i][j]);
+                expected.push(row_totals[i] * col_totals[j] / n);
+            }
+        }
+        let title = "H0: rows and columns are independent".to_string();
+        let freedom = (r as u64 - 1) * (c as u64 - 1);
+        let test = ChiSquaredTest::new(title, labels, observed, expected, 0)?;
+        Ok(ChiSquaredTest { freedom, ..test })
+    }
+
+    /// `estimated` is the number of parameters estimated from the data
+    fn new(
+        title: String,
+        labels: Vec<String>,
+        observed: Vec<f64>,
+        expected: Vec<f64>,
+        estimated: u64,
+    ) -> Result<ChiSquaredTest> {
+        if observed.iter().any(|v| *v < 0.0) {
+            return err("Observed counts cannot be negative.");
+        }
+        if expected.iter().any(|v| *v <= 0.0) {
+            return err("Expected counts must be positive.");
+        }
+        let freedom = (observed.len() as u64).checked_sub(1 + estimated);
+        match freedom {
+            Some(freedom) if freedom > 0 => Ok(ChiSquaredTest {
+                title,
+                labels,
+                observed,
+                expected,
+                freedom,
+            }),
+            _ => err("Not enough categories for a chi-squared test."),
+        }
+    }
+
+    pub fn statistic(&self) -> f64 {
+        let o = self.observed.iter();
+        o.zip(&self.expected).map(|(o, e)| (o - e) * (o - e) / e).sum()
+    }
+
+    pub fn export(&self) -> Result<LineList> {
+        let dist = ChiSquared::new(self.freedom)?;
+        let mut list = LineList::new();
+        list.set_title(&self.title);
+        self.labels.iter().zip(&self.expected).for_each(|(label, e)| {
+            list.push(&format!("expected @ {label}"), *e);
+        });
+        list.push("χ² statistic", self.statistic());
+        list.push("degrees of freedom", self.freedom as f64);
+        list.push("p-value", 1.0 - dist.cdf(self.statistic()));
+        let small = self.expected.iter().filter(|v| **v < 5.0).count();
+        if small > 0 {
+            list.header(&format!(
+                "warning: {small} expected count(s) below 5, \
+                 the χ² approximation may be poor"
+            ));
+        }
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    use crate::distributions::Poisson;
+
+    let rows = [vec![10.0], vec![20.0], vec![30.0]];
+    let test = ChiSquaredTest::goodness_of_fit(&rows)?;
+    float_eq!(test.statistic(), 10);
+    assert_eq!(test.freedom, 2);
+
+    let rows = [vec![10.0, 0.2], vec![20.0, 0.3], vec![30.0, 0.5]];
+    let test = ChiSquaredTest::goodness_of_fit(&rows)?;
+    float_eq!(test.statistic(), 0.5555555556);
+
+    let rows = [vec![0.0, 5.0], vec![1.0, 10.0], vec![2.0, 5.0]];
+    let test = ChiSquaredTest::fit(&rows, Poisson::new)?;
+    float_eq!(total(&test.expected), 20);
+    assert_eq!(test.freedom, 1);
+
+    let rows = [vec![10.0, 20.0], vec![30.0, 40.0]];
+    let test = ChiSquaredTest::independence(&rows)?;
+    float_eq!(test.statistic(), 0.7936507937);
+    assert_eq!(test.freedom, 1);
+
+    assert!(ChiSquaredTest::goodness_of_fit(&[vec![1.0]]).is_err());
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::confidence::label;
+use crate::data_set::Data;
+use crate::display::LineList;
+use crate::distributions::FisherSnedecor;
+use crate::hypothesis::p_values;
+use crate::inverse::Invert;
+use crate::utils::{err, Result};
+
+/// An F-test of H0: σ1² = σ2², using samples from two normal
+/// populations
+pub struct FTest {
+    n1: f64,
+    v1: f64,
+    n2: f64,
+    v2: f64,
+}
+
+impl FTest {
+    pub fn new(d1: &mut Data, d2: &mut Data) -> Result<FTest> {
+        let (n1, n2) = (d1.values()?.len() as f64, d2.values()?.len() as f64);
+        if n1 < 2.0 || n2 < 2.0 {
+            return err("F-tests need at least 2 data points per sample.");
+        }
+        // Data takes a variance that is only rounding error to be 0
+        let (v1, v2) = (d1.var_s()?, d2.var_s()?);
+        if v2 == 0.0 {
+            return err("The second sample has no variance.");
+        }
+        Ok(FTest { n1, v1, n2, v2 })
+    }
+
+    pub fn f(&self) -> f64 {
+        self.v1 / self.v2
+    }
+
+    fn dist(&self) -> Result<FisherSnedecor> {
+        FisherSnedecor::new(self.n1 as u64 - 1, self.n2 as u64 - 1)
+    }
+
+    pub fn export(&self, alpha: f64) -> Result<LineList> {
+        let dist = self.dist()?;
+        let (a, b) = dist.bounds(1.0 - alpha);
+        let label = label(alpha);
+        let mut list = LineList::new();
+        list.set_title("H0: σ1² = σ2²");
+        list.push("[1] sample size", self.n1);
+        list.push("[1] sample variance", self.v1);
+        list.push("[2] sample size", self.n2);
+        list.push("[2] sample variance", self.v2);
+        list.push("F statistic", self.f());
+        list.push("degrees of freedom 1", self.n1 - 1.0);
+        list.push("degrees of freedom 2", self.n2 - 1.0);
+        list.append(&p_values(&dist, self.f()));
+        list.header(&format!("{label} for σ1²/σ2²"));
+        list.push(&format!("{label} lower bound"), self.f() / b);
+        list.push(&format!("{label} upper bound"), self.f() / a);
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    use crate::data_set::Parser;
+    let data = |v: &[&str]| {
+        let raw = v.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        Data::new(&raw, Parser::Single)
+    };
+    let mut d1 = data(&["10", "20", "30", "50"])?;
+    let mut d2 = data(&["9", "1", "4", "5"])?;
+
+    let test = FTest::new(&mut d1, &mut d2)?;
+    float_eq!(test.f(), 26.7175572519);
+
+    let mut d3 = data(&["1", "1", "1"])?;
+    assert!(FTest::new(&mut d1, &mut d3).is_err());
+    assert!(FTest::new(&mut d1, &mut data(&["1"])?).is_err());
+    let mut same = data(&["5", "5", "5"])?;
+    assert!(FTest::new(&mut d1, &mut same).is_err());
+    float_eq!(FTest::new(&mut same, &mut d1)?.f(), 0.0);
+    assert!(FTest::new(&mut d1, &mut data(&["10 0.5", "20 0.5"])?).is_err());
+    Ok(())
+}
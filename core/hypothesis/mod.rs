@@ -0,0 +1,96 @@
+use crate::display::LineList;
+use crate::distributions::Continuous;
+use crate::math::Round;
+use crate::Area;
+
+mod anova;
+mod chi_squared;
+mod f;
+mod nonparametric;
+mod proportion;
+mod t;
+mod z;
+
+pub use anova::Anova;
+pub use chi_squared::ChiSquaredTest;
+pub use f::FTest;
+pub use nonparametric::{mann_whitney, sign_test, signed_rank};
+pub use proportion::{one_proportion, two_proportions};
+pub use t::TTest;
+pub use z::ZTest;
+
+/// lists the size of each sample involved in a test
+fn sizes(sizes: &[f64]) -> LineList {
+    let mut list = LineList::new();
+    match sizes {
+        [n] => list.push("sample size", *n),
+        sizes => sizes.iter().enumerate().for_each(|(i, n)| {
+            list.push(&format!("[{}] sample size", i + 1), *n)
+        }),
+    }
+    list
+}
+
+/// p-values of an observed test statistic `stat` for each of the
+/// three alternative hypotheses, using the null distribution `dist`.
+pub fn p_values(dist: &impl Continuous, stat: f64) -> LineList {
+    let mut list = LineList::new();
+    [Area::Left, Area::Right, Area::Mid]
+        .into_iter()
+        .for_each(|area| list.append(&p_value(dist, stat, area)));
+    list
+}
+
+/// p-value of an observed test statistic `stat` for the alternative
+/// hypothesis that lies in `area`. The two-tailed p-value doubles
+/// the smaller tail.
+pub fn p_value(dist: &impl Continuous, stat: f64, area: Area) -> LineList {
+    let mut list = LineList::new();
+    let left = dist.cdf(stat);
+    match area {
+        Area::Left => list.push("left-tailed p-value", left),
+        Area::Right => list.push("right-tailed p-value", 1.0 - left),
+        Area::Mid => {
+            list.push("two-tailed p-value", 2.0 * left.min(1.0 - left))
+        }
+    }
+    list
+}
+
+/// rejection region of a test at significance level alpha, and
+/// whether or not the observed test statistic `stat` (named `name`)
+/// falls in it. `dist` is assumed to be symmetric about zero.
+pub fn rejection(
+    dist: &impl Continuous,
+    name: &str,
+    stat: f64,
+    area: Area,
+    alpha: f64,
+) -> LineList {
+    let mut list = LineList::new();
+    let reject = match area {
+        Area::Left => {
+            let c = dist.inv_cdf(alpha);
+            list.push(&format!("reject H0 if {name} <"), c);
+            stat < c
+        }
+        Area::Right => {
+            let c = dist.inv_cdf(1.0 - alpha);
+            list.push(&format!("reject H0 if {name} >"), c);
+            stat > c
+        }
+        Area::Mid => {
+            let c = dist.inv_cdf(1.0 - alpha / 2.0);
+            list.push(&format!("reject H0 if |{name}| >"), c);
+            stat.abs() > c
+        }
+    };
+    let level = (alpha * 100.0).roundn(10);
+    match reject {
+        true => list.header(&format!("reject H0 at {level}% significance")),
+        false => {
+            list.header(&format!("do not reject H0 at {level}% significance"))
+        }
+    }
+    list
+}
@@ -0,0 +1,194 @@
+use crate::display::LineList;
+use crate::distributions::{Binomial, Discrete, Normal};
+use crate::hypothesis::{p_values, sizes};
+use crate::math::ranks;
+use crate::utils::{err, Result};
+
+/// largest sample size for which exact p-values are calculated
+const EXACT: usize = 50;
+
+/// left-tailed, right-tailed and two-tailed p-values from the exact
+/// probabilities P(S <= s) and P(S >= s) of a statistic S
+fn exact(left: f64, right: f64) -> LineList {
+    let mut list = LineList::new();
+    list.header("exact p-values");
+    list.push("left-tailed p-value", left.min(1.0));
+    list.push("right-tailed p-value", right.min(1.0));
+    list.push("two-tailed p-value", (2.0 * left.min(right)).min(1.0));
+    list
+}
+
+/// p-values of a statistic with the given mean and variance under H0,
+/// using the normal approximation
+fn approximate(stat: f64, mean: f64, var: f64) -> Result<LineList> {
+    let z = (stat - mean) / var.sqrt();
+    let mut list = LineList::new();
+    list.header("normal approximation");
+    list.push("z statistic", z);
+    list.append(&p_values(&Normal::new(0.0, 1.0)?, z));
+    Ok(list)
+}
+
+/// Σ (t³ - t) over the sizes t of each group of tied values
+fn ties(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|v| (v.len() as f64).powi(3) - v.len() as f64)
+        .sum()
+}
+
+/// Sign test of H0: median = `median`. Values equal to the median
+/// are dropped, and the number of values above it is Binomial(n, 0.5)
+pub fn sign_test(values: &[f64], median: f64, title: &str) -> Result<LineList> {
+    let above = values.iter().filter(|v| **v > median).count() as u64;
+    let below = values.iter().filter(|v| **v < median).count() as u64;
+    let n = above + below;
+    if n == 0 {
+        return err("every value equals the hypothesized median.");
+    }
+    let dist = Binomial::new(n, 0.5)?;
+    let right = match above {
+        0 => 1.0,
+        a => 1.0 - dist.cdf(a - 1),
+    };
+    let mut list = LineList::new();
+    list.set_title(title);
+    list.push("sample size", n as f64);
+    list.push("values above median", above as f64);
+    list.push("values below median", below as f64);
+    list.append(&exact(dist.cdf(above), right));
+    Ok(list)
+}
+
+/// number of subsets of {1, ..., n} with each sum from 0 to n(n+1)/2
+fn rank_sums(n: usize) -> Vec<f64> {
+    let mut counts = vec![1.0];
+    for rank in 1..=n {
+        let mut next = counts.clone();
+        next.resize(counts.len() + rank, 0.0);
+        counts.iter().enumerate().for_each(|(s, c)| next[s + rank] += c);
+        counts = next;
+    }
+    counts
+}
+
+/// Wilcoxon signed-rank test of H0: the distribution is symmetric
+/// about `median`. W+ is the sum of the ranks of |x - median| over
+/// values above the median, and values equal to it are dropped.
+pub fn signed_rank(
+    values: &[f64],
+    median: f64,
+    title: &str,
+) -> Result<LineList> {
+    let diffs: Vec<_> =
+        values.iter().map(|v| v - median).filter(|v| *v != 0.0).collect();
+    let n = diffs.len();
+    if n == 0 {
+        return err("every value equals the hypothesized median.");
+    }
+    let abs: Vec<_> = diffs.iter().map(|v| v.abs()).collect();
+    let ranks = ranks(&abs);
+    let w: f64 =
+        ranks.iter().zip(&diffs).filter(|v| *v.1 > 0.0).map(|v| v.0).sum();
+    let total = (n * (n + 1)) as f64 / 2.0;
+
+    let mut list = LineList::new();
+    list.set_title(title);
+    list.push("sample size", n as f64);
+    list.push("W+ statistic", w);
+    list.push("W- statistic", total - w);
+    let tied = ties(&abs);
+    if n <= EXACT && tied == 0.0 {
+        let counts = rank_sums(n);
+        let all = 2f64.powi(n as i32);
+        let w = w as usize;
+        let left = counts[..=w].iter().sum::<f64>() / all;
+        let right = counts[w..].iter().sum::<f64>() / all;
+        list.append(&exact(left, right));
+    } else {
+        let n = n as f64;
+        let var = n * (n + 1.0) * (2.0 * n + 1.0) / 24.0 - tied / 48.0;
+        list.append(&approximate(w, total / 2.0, var)?);
+    }
+    Ok(list)
+}
+
+/// distribution of the Mann-Whitney U statistic of samples of sizes
+/// m and n without ties, as the number of orderings with each U
+fn u_counts(m: usize, n: usize) -> Vec<f64> {
+    // row[j]: counts for samples of sizes i and j, where U counts pairs
+    // in which the value from the first sample is larger
+    let mut row: Vec<Vec<f64>> = vec![vec![1.0]; n + 1];
+    for i in 1..=m {
+        let mut next = vec![vec![1.0]];
+        for j in 1..=n {
+            let mut counts = vec![0.0; i * j + 1];
+            // the largest value is from the first sample, beating all j
+            row[j].iter().enumerate().for_each(|(u, c)| counts[u + j] += c);
+            // the largest value is from the second sample
+            next[j - 1].iter().enumerate().for_each(|(u, c)| counts[u] += c);
+            next.push(counts);
+        }
+        row = next;
+    }
+    row.swap_remove(n)
+}
+
+/// Mann-Whitney U test of H0: both samples come from the same
+/// distribution. U counts the pairs in which the value from the first
+/// sample is larger, with ties counting one half.
+pub fn mann_whitney(x: &[f64], y: &[f64]) -> Result<LineList> {
+    let (m, n) = (x.len(), y.len());
+    if m == 0 || n == 0 {
+        return err("empty dataset.");
+    }
+    let all: Vec<_> = x.iter().chain(y).cloned().collect();
+    let ranks = ranks(&all);
+    let r1: f64 = ranks[..m].iter().sum();
+    let u = r1 - (m * (m + 1)) as f64 / 2.0;
+    let pairs = (m * n) as f64;
+
+    let mut list = LineList::new();
+    list.set_title("H0: both samples come from the same distribution");
+    list.append(&sizes(&[m as f64, n as f64]));
+    list.push("[1] rank sum", r1);
+    list.push("U statistic", u);
+    let tied = ties(&all);
+    if m + n <= EXACT && tied == 0.0 {
+        let counts = u_counts(m, n);
+        let total: f64 = counts.iter().sum();
+        let u = u as usize;
+        let left = counts[..=u].iter().sum::<f64>() / total;
+        let right = counts[u..].iter().sum::<f64>() / total;
+        list.append(&exact(left, right));
+    } else {
+        let big = (m + n) as f64;
+        let var = pairs / 12.0 * (big + 1.0 - tied / (big * (big - 1.0)));
+        list.append(&approximate(u, pairs / 2.0, var)?);
+    }
+    Ok(list)
+}
+
+#[test]
+fn test() -> Result<()> {
+    assert_eq!(rank_sums(3), vec![1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0]);
+    assert_eq!(u_counts(2, 2), vec![1.0, 1.0, 2.0, 1.0, 1.0]);
+    assert_eq!(u_counts(1, 3), vec![1.0; 4]);
+    float_eq!(ties(&[1.0, 2.0, 2.0, 3.0, 3.0, 3.0]), 30.0);
+
+    let last = |list: LineList| list.list.last().and_then(|v| v.val);
+    let x = [1.83, 0.5, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.3];
+    let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.15, 1.29];
+    let d: Vec<_> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    // W+ = 40, exact two-tailed p-value 0.0390625
+    float_eq!(last(signed_rank(&d, 0.0, "")?).unwrap(), 0.0390625);
+    // 7 of 9 above, two-tailed p-value 2 × P(X >= 7)
+    float_eq!(last(sign_test(&d, 0.0, "")?).unwrap(), 0.1796875);
+    float_eq!(
+        last(mann_whitney(&[1.0, 2.0], &[3.0, 4.0])?).unwrap(),
+        1.0 / 3.0
+    );
+    Ok(())
+}
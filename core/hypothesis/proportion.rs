@@ -0,0 +1,148 @@
+use crate::confidence::{symmetric, wins};
+use crate::display::LineList;
+use crate::distributions::{Binomial, Discrete, Distribution, Normal};
+use crate::hypothesis::{p_value, p_values, sizes};
+use crate::utils::{err, Result};
+use crate::Area;
+
+/// the first k in lo..hi at which `past` holds, or hi if it never
+/// does, given that it goes on holding from there
+fn first<F: Fn(u64) -> bool>(mut lo: u64, mut hi: u64, past: F) -> u64 {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match past(mid) {
+            true => hi = mid,
+            false => lo = mid + 1,
+        }
+    }
+    lo
+}
+
+/// p-values of the exact binomial test of H0: p = p0, given x wins
+/// out of n trials. The two-tailed p-value sums the probabilities of
+/// every count no more likely than x. As the p.m.f. rises to the mode
+/// and falls after it, those counts make up two tails, whose ends are
+/// found by bisection so that large n need not visit every count.
+fn exact(x: u64, n: u64, p0: f64) -> Result<LineList> {
+    let dist = Binomial::new(n, p0)?;
+    let right = match x {
+        0 => 1.0,
+        x => dist.sf(x - 1),
+    };
+    // allow for rounding errors in comparing probabilities
+    let cutoff = dist.pmf(x) * (1.0 + 1e-7);
+    let mode = dist.mode().unwrap() as u64;
+    let two = match dist.pmf(mode) <= cutoff {
+        true => 1.0,
+        false => {
+            // the more likely counts run from lo to hi
+            let lo = first(0, mode, |k| dist.pmf(k) > cutoff);
+            let hi = first(mode, n + 1, |k| dist.pmf(k) <= cutoff) - 1;
+            let below = match lo {
+                0 => 0.0,
+                lo => dist.cdf(lo - 1),
+            };
+            below + dist.sf(hi)
+        }
+    };
+    let mut list = LineList::new();
+    list.header("exact binomial");
+    list.push("left-tailed p-value", dist.cdf(x));
+    list.push("right-tailed p-value", right);
+    list.push("two-tailed p-value", two.min(1.0));
+    Ok(list)
+}
+
+/// One-sample test of H0: p = p0, given x wins out of n trials, both
+/// exactly and with the normal approximation. With the continuity
+/// correction, each tail of the approximation takes in half a win more
+/// than x, and the z statistic moves x half a win towards its expected
+/// value for the two-tailed p-value.
+pub fn one_proportion(x: u64, n: u64, p0: f64) -> Result<LineList> {
+    wins(x, n)?;
+    if p0 <= 0.0 || p0 >= 1.0 {
+        return err("The hypothesized proportion must be between 0 and 1.");
+    }
+    let (k, m) = (x as f64, n as f64);
+    let std_err = (p0 * (1.0 - p0) / m).sqrt();
+    let diff = k - m * p0;
+    let z = diff.signum() * (diff.abs() - 0.5).max(0.0) / m / std_err;
+    let shifted = |wins: f64| (diff + wins) / m / std_err;
+    let dist = Normal::new(0.0, 1.0)?;
+    let mut list = LineList::new();
+    list.set_title(&format!("H0: p = {p0}"));
+    list.push("sample size", m);
+    list.push("point estimate", k / m);
+    list.append(&exact(x, n, p0)?);
+    list.header("normal approximation");
+    list.push("standard error", std_err);
+    list.push("z statistic", z);
+    list.append(&p_value(&dist, shifted(0.5), Area::Left));
+    list.append(&p_value(&dist, shifted(-0.5), Area::Right));
+    list.append(&p_value(&dist, z, Area::Mid));
+    Ok(list)
+}
+
+/// Two-sample z-test of H0: p1 = p2, given x1 wins out of n1 trials
+/// and x2 wins out of n2 trials, using the pooled proportion. The
+/// confidence interval for p1 - p2 uses the unpooled standard error.
+pub fn two_proportions(
+    (x1, n1): (u64, u64),
+    (x2, n2): (u64, u64),
+    alpha: f64,
+) -> Result<LineList> {
+    wins(x1, n1)?;
+    wins(x2, n2)?;
+    let (x1, n1, x2, n2) = (x1 as f64, n1 as f64, x2 as f64, n2 as f64);
+    let (p1, p2) = (x1 / n1, x2 / n2);
+    let pooled = (x1 + x2) / (n1 + n2);
+    let std_err = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+    if std_err == 0.0 {
+        return err("The samples are all wins or all losses.");
+    }
+    let z = (p1 - p2) / std_err;
+    let unpooled = (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt();
+    let dist = Normal::new(0.0, 1.0)?;
+    let mut list = LineList::new();
+    list.set_title("H0: p1 = p2");
+    list.append(&sizes(&[n1, n2]));
+    list.push("[1] point estimate", p1);
+    list.push("[2] point estimate", p2);
+    list.push("pooled proportion", pooled);
+    list.push("standard error", std_err);
+    list.push("z statistic", z);
+    list.append(&p_values(&dist, z));
+    list.header("p1 - p2");
+    list.push("point estimate", p1 - p2);
+    list.push("standard error", unpooled);
+    list.append(&symmetric(&dist, alpha, p1 - p2, unpooled));
+    Ok(list)
+}
+
+#[test]
+fn test() -> Result<()> {
+    let value = |list: &LineList, desc: &str| {
+        list.list.iter().find(|v| v.desc == desc).and_then(|v| v.val)
+    };
+    let test = one_proportion(7, 20, 0.5)?;
+    float_eq!(value(&test, "left-tailed p-value").unwrap(), 0.1315879822);
+    // the exact two-tailed p-value doubles a tail when p0 = 0.5
+    float_eq!(value(&test, "two-tailed p-value").unwrap(), 0.2631759644);
+    float_eq!(value(&test, "z statistic").unwrap(), -1.1180339887);
+    // each tail of the approximation is corrected on its own
+    let right = test.list.iter().rev().find(|v| v.desc.starts_with("right"));
+    float_eq!(right.unwrap().val.unwrap(), 0.9412375659);
+    // the tails of unlikely counts lie on both sides of the mode
+    let test = one_proportion(3, 100, 0.1)?;
+    float_eq!(value(&test, "two-tailed p-value").unwrap(), 0.0178437664);
+    let test = one_proportion(50_000_000, 100_000_000, 0.5)?;
+    assert_eq!(value(&test, "two-tailed p-value").unwrap(), 1.0);
+
+    let test = two_proportions((45, 100), (30, 100), 0.05)?;
+    float_eq!(value(&test, "z statistic").unwrap(), 2.1908902300);
+
+    assert!(one_proportion(3, 2, 0.5).is_err());
+    assert!(one_proportion(1, 2, 1.0).is_err());
+    assert!(two_proportions((0, 5), (0, 5), 0.05).is_err());
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::confidence::symmetric;
+use crate::data_set::Data;
+use crate::display::LineList;
+use crate::distributions::StudentsT;
+use crate::hypothesis::{p_values, sizes};
+use crate::math;
+use crate::utils::{err, Result};
+
+/// A t-test of H0: (estimated parameter) = null
+pub struct TTest {
+    title: String,
+    /// sample sizes of each sample involved
+    sizes: Vec<f64>,
+    /// point estimate of the tested parameter
+    estimate: f64,
+    /// hypothesized value of the tested parameter
+    null: f64,
+    std_err: f64,
+    freedom: f64,
+}
+
+/// sample size, mean and sample variance, with a check that there is
+/// enough data to estimate the variance
+fn summary(d: &mut Data) -> Result<(f64, f64, f64)> {
+    let n = d.values()?.len() as f64;
+    if n < 2.0 {
+        return err("t-tests need at least 2 data points per sample.");
+    }
+    Ok((n, d.mean()?, d.var_s()?))
+}
+
+impl TTest {
+    /// one-sample t-test of H0: μ = mean
+    pub fn one_sample(d: &mut Data, mean: f64) -> Result<TTest> {
+        let (n, m, v) = summary(d)?;
+        Ok(TTest {
+            title: format!("H0: μ = {mean}"),
+            sizes: vec![n],
+            estimate: m,
+            null: mean,
+            std_err: (v / n).sqrt(),
+            freedom: n - 1.0,
+        })
+    }
+
+    /// paired t-test of H0: μd = mean, where `d` holds the
+    /// differences of each pair
+    pub fn paired(d: &mut Data, mean: f64) -> Result<TTest> {
+        let test = TTest::one_sample(d, mean)?;
+        Ok(TTest { title: format!("H0: μd = {mean}"), ..test })
+    }
+
+    /// two-sample t-test of H0: μ1 - μ2 = diff
+    /// assuming equal population variances
+    pub fn pooled(d1: &mut Data, d2: &mut Data, diff: f64) -> Result<TTest> {
+        let ((n1, m1, v1), (n2, m2, v2)) = (summary(d1)?, summary(d2)?);
+        let pooled = math::pool(n1, v1, n2, v2);
+        Ok(TTest {
+            title: format!("H0: μ1 - μ2 = {diff} (pooled)"),
+            sizes: vec![n1, n2],
+            estimate: m1 - m2,
+            null: diff,
+            std_err: (pooled * (1.0 / n1 + 1.0 / n2)).sqrt(),
+            freedom: n1 + n2 - 2.0,
+        })
+    }
+
+    /// two-sample t-test of H0: μ1 - μ2 = diff
+    /// without assuming equal population variances
+    pub fn welch(d1: &mut Data, d2: &mut Data, diff: f64) -> Result<TTest> {
+        let ((n1, m1, v1), (n2, m2, v2)) = (summary(d1)?, summary(d2)?);
+        let (a, b) = (v1 / n1, v2 / n2);
+        // Welch–Satterthwaite approximation
+        let freedom =
+            (a + b).powi(2) / (a * a / (n1 - 1.0) + b * b / (n2 - 1.0));
+        Ok(TTest {
+            title: format!("H0: μ1 - μ2 = {diff} (Welch)"),
+            sizes: vec![n1, n2],
+            estimate: m1 - m2,
+            null: diff,
+            std_err: (a + b).sqrt(),
+            freedom,
+        })
+    }
+
+    pub fn t(&self) -> f64 {
+        (self.estimate - self.null) / self.std_err
+    }
+
+    /// confidence interval for the tested parameter
+    pub fn interval(&self, alpha: f64) -> Result<LineList> {
+        let dist = StudentsT::with_freedom(self.freedom)?;
+        let mut list = LineList::new();
+        list.append(&sizes(&self.sizes));
+        list.push("point estimate", self.estimate);
+        list.push("standard error", self.std_err);
+        list.push("degrees of freedom", self.freedom);
+        list.append(&symmetric(&dist, alpha, self.estimate, self.std_err));
+        Ok(list)
+    }
+
+    /// Fails if the data has no variance, as t = 0/0 or ±∞ has no
+    /// p-value.
+    pub fn export(&self, alpha: f64) -> Result<LineList> {
+        if !self.t().is_finite() {
+            return err("The data has no variance, so t is undefined.");
+        }
+        let dist = StudentsT::with_freedom(self.freedom)?;
+        let mut list = LineList::new();
+        list.set_title(&self.title);
+        list.append(&sizes(&self.sizes));
+        list.push("point estimate", self.estimate);
+        list.push("standard error", self.std_err);
+        list.push("t statistic", self.t());
+        list.push("degrees of freedom", self.freedom);
+        list.append(&p_values(&dist, self.t()));
+        list.append(&symmetric(&dist, alpha, self.estimate, self.std_err));
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    use crate::data_set::Parser;
+    let data = |v: &[&str]| {
+        let raw = v.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        Data::new(&raw, Parser::Single)
+    };
+    let mut d1 = data(&["10", "20", "30", "50"])?;
+    let mut d2 = data(&["9", "1", "4", "5"])?;
+
+    let test = TTest::one_sample(&mut d1, 20.0)?;
+    float_eq!(test.t(), 0.878310065653);
+    float_eq!(test.freedom, 3);
+
+    let test = TTest::pooled(&mut d1, &mut d2, 0.0)?;
+    float_eq!(test.t(), 2.615705799076);
+    float_eq!(test.freedom, 6);
+
+    let test = TTest::welch(&mut d1, &mut d2, 0.0)?;
+    float_eq!(test.t(), 2.615705799076);
+    float_eq!(test.freedom, 3.2242572670, 8);
+
+    assert!(TTest::one_sample(&mut data(&["10"])?, 0.0).is_err());
+    let mut same = data(&["5", "5", "5"])?;
+    assert!(TTest::one_sample(&mut same, 4.0)?.export(0.05).is_err());
+    // their mean is not exactly 3.3, which leaves a little variance
+    let mut same = data(&["3.3"; 6])?;
+    assert!(TTest::one_sample(&mut same, 3.0)?.export(0.05).is_err());
+    let mut weighted = data(&["10 0.5", "20 0.5"])?;
+    assert!(TTest::one_sample(&mut weighted, 0.0).is_err());
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::confidence::symmetric;
+use crate::display::LineList;
+use crate::distributions::Normal;
+use crate::hypothesis::{p_value, rejection, sizes};
+use crate::utils::{err, Result};
+use crate::Area;
+
+/// A z-test of H0: (estimated parameter) = null,
+/// where the population variances are known
+pub struct ZTest {
+    title: String,
+    /// sample sizes of each sample involved
+    sizes: Vec<f64>,
+    /// point estimate of the tested parameter
+    estimate: f64,
+    /// hypothesized value of the tested parameter
+    null: f64,
+    std_err: f64,
+}
+
+fn check(sigma: f64, n: f64) -> Result<()> {
+    if sigma <= 0.0 {
+        return err("Standard deviation must be positive.");
+    }
+    if n < 1.0 {
+        return err("Sample size must be positive.");
+    }
+    Ok(())
+}
+
+impl ZTest {
+    /// one-sample z-test of H0: μ = mean, given a sample of size n
+    /// with sample mean m from a population with std.dev sigma
+    pub fn one_sample(m: f64, sigma: f64, n: f64, mean: f64) -> Result<ZTest> {
+        check(sigma, n)?;
+        Ok(ZTest {
+            title: format!("H0: μ = {mean}"),
+            sizes: vec![n],
+            estimate: m,
+            null: mean,
+            std_err: sigma / n.sqrt(),
+        })
+    }
+
+    /// two-sample z-test of H0: μ1 - μ2 = diff, with each sample
+    /// given as (sample mean, population std.dev, sample size)
+    pub fn two_sample(
+        (m1, s1, n1): (f64, f64, f64),
+        (m2, s2, n2): (f64, f64, f64),
+        diff: f64,
+    ) -> Result<ZTest> {
+        check(s1, n1)?;
+        check(s2, n2)?;
+        Ok(ZTest {
+            title: format!("H0: μ1 - μ2 = {diff}"),
+            sizes: vec![n1, n2],
+            estimate: m1 - m2,
+            null: diff,
+            std_err: (s1 * s1 / n1 + s2 * s2 / n2).sqrt(),
+        })
+    }
+
+    pub fn z(&self) -> f64 {
+        (self.estimate - self.null) / self.std_err
+    }
+
+    /// confidence interval for the tested parameter
+    pub fn interval(&self, alpha: f64) -> Result<LineList> {
+        let dist = Normal::new(0.0, 1.0)?;
+        let mut list = LineList::new();
+        list.append(&sizes(&self.sizes));
+        list.push("point estimate", self.estimate);
+        list.push("standard error", self.std_err);
+        list.append(&symmetric(&dist, alpha, self.estimate, self.std_err));
+        Ok(list)
+    }
+
+    pub fn export(&self, area: Area, alpha: f64) -> Result<LineList> {
+        let dist = Normal::new(0.0, 1.0)?;
+        let mut list = LineList::new();
+        list.set_title(&self.title);
+        list.append(&sizes(&self.sizes));
+        list.push("point estimate", self.estimate);
+        list.push("standard error", self.std_err);
+        list.push("z statistic", self.z());
+        list.append(&p_value(&dist, self.z(), area));
+        list.append(&rejection(&dist, "z", self.z(), area, alpha));
+        list.append(&symmetric(&dist, alpha, self.estimate, self.std_err));
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    let test = ZTest::one_sample(10.5, 2.0, 16.0, 10.0)?;
+    float_eq!(test.z(), 1);
+
+    let test = ZTest::two_sample((5.0, 3.0, 9.0), (4.0, 4.0, 16.0), 0.0)?;
+    float_eq!(test.z(), std::f64::consts::FRAC_1_SQRT_2);
+
+    assert!(ZTest::one_sample(10.0, 0.0, 4.0, 10.0).is_err());
+    assert!(ZTest::one_sample(10.0, 1.0, 0.0, 10.0).is_err());
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use crate::display::LineList;
-use crate::distributions::{Continuous, Distribution};
-use crate::math::Round;
+use crate::distributions::{Continuous, Discrete, Distribution};
 use crate::Area;
 
 pub trait Invert {
     fn invert(&self, area: Area, p: f64) -> LineList;
+
+    /// bounds (a, b) of the central region where P(a < X < b) = p
+    fn bounds(&self, p: f64) -> (f64, f64);
 }
 
 impl<D: Distribution + Continuous> Invert for D {
@@ -14,21 +16,113 @@ impl<D: Distribution + Continuous> Invert for D {
         match area {
             Area::Left => {
                 let x = self.inv_cdf(p);
-                list.push("x: right bound", x.roundn(10));
+                list.push("x: right bound", x);
                 list.push("P(X > x)", p);
             }
             Area::Right => {
                 let x = self.inv_cdf(1.0 - p);
-                list.push("x: left bound", x.roundn(10));
+                list.push("x: left bound", x);
                 list.push("P(X > x)", p);
             }
             Area::Mid => {
-                let a = (1.0 - p) / 2.0;
-                list.push("a: left bound", self.inv_cdf(a));
-                list.push("b: right bound", self.inv_cdf(1.0 - a));
+                let (a, b) = self.bounds(p);
+                list.push("a: left bound", a);
+                list.push("b: right bound", b);
                 list.push("P(a < X < b)", p);
             }
         }
         list
     }
+
+    fn bounds(&self, p: f64) -> (f64, f64) {
+        let a = (1.0 - p) / 2.0;
+        (self.inv_cdf(a), self.inv_cdf(1.0 - a))
+    }
+}
+
+pub trait InvertDiscrete {
+    /// smallest x such that P(X <= x) >= p
+    fn quantile(&self, p: f64) -> u64;
+
+    fn invert(&self, area: Area, p: f64) -> LineList;
+}
+
+/// smallest x such that pred(P(X <= x)) holds, where pred is
+/// monotone: once true, it stays true for all larger x
+fn search<D: Discrete, F: Fn(f64) -> bool>(dist: &D, pred: F) -> u64 {
+    // exponential search for an upper bound
+    let mut hi = 1;
+    while !pred(dist.cdf(hi)) && hi < u64::MAX / 2 {
+        hi *= 2;
+    }
+    // binary search for the first x in [0, hi] that satisfies pred
+    let mut lo = 0;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match pred(dist.cdf(mid)) {
+            true => hi = mid,
+            false => lo = mid + 1,
+        }
+    }
+    lo
+}
+
+impl<D: Distribution + Discrete> InvertDiscrete for D {
+    fn quantile(&self, p: f64) -> u64 {
+        search(self, |c| c >= p)
+    }
+
+    fn invert(&self, area: Area, p: f64) -> LineList {
+        let mut list = LineList::new();
+        list.set_title(&self.title());
+        // P(X < x)
+        let below = |x: u64| x.checked_sub(1).map_or(0.0, |v| self.cdf(v));
+        // P(X >= x)
+        let above = |x: u64| x.checked_sub(1).map_or(1.0, |v| self.sf(v));
+        match area {
+            Area::Left => {
+                let x = self.quantile(p);
+                list.push("x: right bound", x as f64);
+                list.push("P(X <= x)", self.cdf(x));
+                list.push("P(X < x)", below(x));
+            }
+            Area::Right => {
+                let x = self.quantile(1.0 - p);
+                list.push("x: left bound", x as f64);
+                list.push("P(X > x)", self.sf(x));
+                list.push("P(X >= x)", above(x));
+            }
+            Area::Mid => {
+                let tail = (1.0 - p) / 2.0;
+                let a = search(self, |c| c > tail);
+                let b = self.quantile(1.0 - tail);
+                list.push("a: left bound", a as f64);
+                list.push("b: right bound", b as f64);
+                list.push("P(a <= X <= b)", self.cdf(b) - below(a));
+                list.push("P(X < a)", below(a));
+                list.push("P(X > b)", self.sf(b));
+            }
+        }
+        list
+    }
+}
+
+#[test]
+fn test() -> crate::utils::Result<()> {
+    use crate::distributions::{Binomial, NegativeBinomial, Poisson};
+
+    let dist = Binomial::new(10, 0.5)?;
+    assert_eq!(dist.quantile(0.0), 0);
+    assert_eq!(dist.quantile(0.5), 5);
+    assert_eq!(dist.quantile(0.62), 5);
+    assert_eq!(dist.quantile(0.63), 6);
+    assert_eq!(dist.quantile(1.0), 10);
+
+    let dist = Poisson::new(1000.0)?;
+    assert_eq!(dist.quantile(0.5), 1000);
+
+    let dist = NegativeBinomial::new(4, 0.5)?;
+    assert_eq!(dist.quantile(0.0), 0);
+    assert_eq!(dist.quantile(1e-9), 4);
+    Ok(())
 }
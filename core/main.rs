@@ -1,52 +1,99 @@
-/// Statistics calculator for entry-level university statistics modules
-/// Distributions supported:
-///
-/// [discrete]
-///   - binomial
-///   - negative binomial
-///   - geometric
-///   - poisson
-///
-/// [continuous]
-///   - uniform
-///   - exponential
-///   - normal
-///   - t (Student's T)
-///   - chi-squared
-///   - f (Fisher-Snedecor)
-///
-/// Other operations supported:
-///
-/// [inversions]
-///   - invert normal
-///   - invert t
-///   - invert chi-squared
-///
-/// [data crunching]
-///   - calculate pooled sample variance from sample sizes and variances
-///   - read a file of numbers
-///   - read a file of value-probability pairs
-///   - read a file of number pairs and analyze difference
-///   - read two files of numbers and compare the samples
-///   - evaluate a math expression
+//! Statistics calculator for entry-level university statistics modules
+//! Distributions supported:
+//!
+//! [discrete]
+//!   - binomial
+//!   - negative binomial
+//!   - geometric
+//!   - poisson
+//!   - hypergeometric
+//!   - discrete uniform
+//!   - Bernoulli
+//!   - categorical and multinomial
+//!   - any distribution of non-negative integers, from a file
+//!
+//! [continuous]
+//!   - uniform
+//!   - exponential
+//!   - normal
+//!   - t (Student's T)
+//!   - chi-squared
+//!   - f (Fisher-Snedecor)
+//!   - gamma
+//!   - beta
+//!   - log-normal
+//!   - Weibull
+//!   - Cauchy
+//!   - Laplace
+//!   - Pareto
+//!
+//! Other operations supported:
+//!
+//! [inversions]
+//!   - invert binomial
+//!   - invert negative binomial
+//!   - invert geometric
+//!   - invert poisson
+//!   - invert normal
+//!   - invert t
+//!   - invert chi-squared
+//!   - invert f
+//!   - invert exponential
+//!   - invert uniform
+//!   - invert gamma, beta, log-normal, Weibull, Cauchy, Laplace, Pareto
+//!
+//! [data crunching]
+//!   - calculate pooled sample variance from sample sizes and variances
+//!   - read a file of numbers
+//!   - read a file of value-probability pairs
+//!   - read a file of number pairs and analyze difference
+//!   - read two files of numbers and compare the samples
+//!   - draw a box plot of a file of numbers, flagging outliers
+//!   - draw a histogram or stem-and-leaf display of a file of numbers
+//!   - evaluate a math expression
+//!
+//! [hypothesis testing]
+//!   - one-sample, paired and two-sample t-tests
+//!   - one-sample and two-sample z-tests
+//!   - chi-squared goodness-of-fit and independence tests
+//!   - F-test for equality of two variances
+//!   - one-way ANOVA, with Tukey HSD or Bonferroni comparisons
+//!   - sign, Wilcoxon signed-rank and Mann-Whitney U tests
+//!   - exact and approximate tests of one or two proportions
+//!
+//! [confidence intervals]
+//!   - mean, difference of means, proportion, variance
+//!   - Wald, Wilson and Clopper-Pearson intervals for a proportion
+//!
+//! [regression]
+//!   - simple linear regression, with confidence and prediction intervals
+//!   - Pearson, Spearman and Kendall correlation
 
 #[macro_use]
 mod macros;
 mod analyze;
+mod confidence;
+mod correlation;
 mod data_set;
 mod display;
 mod distributions;
+mod expect;
 mod help;
+mod hypothesis;
 mod inverse;
 mod math;
+mod plot;
 mod printer;
+mod regression;
 mod secret;
 mod utils;
 
 use analyze::Analyze;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use display::LineList;
-use inverse::Invert;
+use expect::Expect;
+use inverse::{Invert, InvertDiscrete};
+use plot::Plot;
 use utils::Result;
 
 #[derive(Parser)]
@@ -54,15 +101,90 @@ use utils::Result;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// output format
+    #[arg(long, value_enum, global = true, default_value_t = Format::Text)]
+    format: Format,
+    /// number of decimal places to show
+    #[arg(long, global = true, default_value_t = 10)]
+    #[arg(value_parser = clap::value_parser!(u64).range(0..=15))]
+    precision: u64,
+    /// number of significant figures to show, instead of decimal places
+    #[arg(long, global = true, conflicts_with = "precision")]
+    #[arg(value_parser = clap::value_parser!(u64).range(1..=17))]
+    sig_figs: Option<u64>,
 }
 
-#[derive(Clone, ValueEnum)]
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// aligned, human-readable text
+    Text,
+    /// a JSON object with unrounded values
+    Json,
+    /// comma-separated values
+    Csv,
+    /// tab-separated values
+    Tsv,
+    /// a GitHub-flavored Markdown table
+    Markdown,
+    /// a LaTeX tabular environment
+    Latex,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
 pub enum Area {
     Left,
     Mid,
     Right,
 }
 
+/// discrete distributions that can be fitted to data
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Fit {
+    Binom,
+    Pois,
+}
+
+/// approximations of discrete distributions
+#[derive(Clone, Copy, ValueEnum)]
+enum Approx {
+    /// normal distribution with the same mean and variance
+    Normal,
+}
+
+/// rules for choosing the bin width of a histogram
+#[derive(Clone, Copy, ValueEnum)]
+pub enum BinRule {
+    /// ⌈log₂ n⌉ + 1 bins
+    Sturges,
+    /// width 3.49 s / ∛n
+    Scott,
+    /// Freedman–Diaconis: width 2 IQR / ∛n
+    Fd,
+}
+
+/// pairwise comparisons of means after an ANOVA
+#[derive(Clone, Copy, ValueEnum)]
+pub enum PostHoc {
+    /// Tukey's honestly significant difference (Tukey-Kramer)
+    Tukey,
+    /// t-tests with Bonferroni-adjusted p-values
+    Bonferroni,
+}
+
+/// options shared by the distribution subcommands
+#[derive(Args)]
+struct Extras {
+    /// draw the distribution, shading the intervals between points
+    #[arg(long)]
+    plot: bool,
+    /// show the median, mode, shape, entropy and higher moments
+    #[arg(long)]
+    moments: bool,
+    /// E[g(X)] and Var[g(X)] of a function g, as an expression in x
+    #[arg(short, long, value_name = "EXPR")]
+    expect: Option<String>,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// X ~ B(n, p)     P(win x times in n tries)
@@ -73,6 +195,11 @@ enum Commands {
         p: f64,
         #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
         x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ NB(k, p)    P(win kth time on the xth try)
@@ -83,6 +210,11 @@ enum Commands {
         p: f64,
         #[arg(value_name = "TRIALS", value_parser = utils::eval_u64)]
         x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ G(p)        P(win once on the xth try)
@@ -91,6 +223,11 @@ enum Commands {
         p: f64,
         #[arg(value_name = "TRIALS", value_parser = utils::eval_u64)]
         x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ Poisson(l)  P(get x hits in interval)
@@ -99,6 +236,98 @@ enum Commands {
         l: f64,
         #[arg(value_name = "HITS", value_parser = utils::eval_u64)]
         x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ H(N, K, n)  P(win x times in n draws without replacement)
+    Hypergeom {
+        #[arg(value_name = "POPULATION", value_parser = utils::eval_u64)]
+        m: u64,
+        /// number of wins in the population
+        #[arg(value_name = "SUCCESSES", value_parser = utils::eval_u64)]
+        k: u64,
+        #[arg(value_name = "DRAWS", value_parser = utils::eval_u64)]
+        n: u64,
+        #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
+        x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ DU(a, b)    Discrete uniform distribution
+    Dunif {
+        #[arg(value_name = "MIN", value_parser = utils::eval_u64)]
+        a: u64,
+        #[arg(value_name = "MAX", value_parser = utils::eval_u64)]
+        b: u64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_u64)]
+        x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ Ber(p)      P(win a single try)
+    Bernoulli {
+        #[arg(value_name = "WIN_RATE", value_parser = utils::eval_prob)]
+        p: f64,
+        #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
+        x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ Cat(p...)   P(land in category x)
+    Categorical {
+        /// comma-separated probabilities of categories 0, 1, 2, ...
+        #[arg(value_name = "PROBABILITIES", value_parser = utils::eval_probs)]
+        p: utils::Probabilities,
+        #[arg(value_name = "CATEGORIES", value_parser = utils::eval_u64)]
+        x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ M(n; p...)  P(land x1, x2, ... times in each category)
+    Multinom {
+        /// comma-separated probabilities of each category
+        #[arg(value_name = "PROBABILITIES", value_parser = utils::eval_probs)]
+        p: utils::Probabilities,
+        /// comma-separated count of each category, summing to n
+        #[arg(value_name = "COUNTS", value_parser = utils::eval_counts)]
+        x: Option<utils::Counts>,
+        /// number of trials (defaults to the sum of the counts)
+        #[arg(short = 'n', long, value_parser = utils::eval_u64)]
+        trials: Option<u64>,
+    },
+
+    /// X ~ FILE        Distribution from a file of values and probabilities
+    #[command(long_about = help::PMF)]
+    Pmf {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_u64)]
+        x: Vec<u64>,
+        /// compare each interval with an approximation
+        #[arg(long, value_enum)]
+        approx: Option<Approx>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ U(a, b)     Uniform distribution
@@ -109,6 +338,8 @@ enum Commands {
         b: f64,
         #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
         x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ Exp(l)      Exponential distribution
@@ -117,6 +348,8 @@ enum Commands {
         l: f64,
         #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
         x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ N(m, s²)    Normal distribution
@@ -127,6 +360,8 @@ enum Commands {
         s: f64,
         #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
         x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ t(n)        Student's t-distribution
@@ -136,6 +371,8 @@ enum Commands {
         f: u64,
         #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
         x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ χ²(n)       Chi-squared distribution
@@ -145,6 +382,8 @@ enum Commands {
         n: u64,
         #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
         x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
     },
 
     /// X ~ F(m, n)     Fisher-Snedecor distribution
@@ -155,6 +394,139 @@ enum Commands {
         n: u64,
         #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
         x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ Gamma(a, b) Gamma distribution
+    Gamma {
+        #[arg(value_name = "SHAPE", value_parser = utils::eval_f64)]
+        a: f64,
+        #[arg(value_name = "RATE", value_parser = utils::eval_f64)]
+        b: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ Beta(a, b)  Beta distribution
+    Beta {
+        #[arg(value_name = "SHAPE_A", value_parser = utils::eval_f64)]
+        a: f64,
+        #[arg(value_name = "SHAPE_B", value_parser = utils::eval_f64)]
+        b: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ LogN(m, s²) Log-normal distribution
+    Lognorm {
+        /// mean of ln(X)
+        #[arg(value_name = "MEAN_LOG", value_parser = utils::eval_f64)]
+        m: f64,
+        /// standard deviation of ln(X)
+        #[arg(value_name = "STD_DEV_LOG", value_parser = utils::eval_f64)]
+        s: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ W(k, l)     Weibull distribution
+    Weibull {
+        #[arg(value_name = "SHAPE", value_parser = utils::eval_f64)]
+        k: f64,
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        l: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ C(x, g)     Cauchy distribution
+    Cauchy {
+        #[arg(value_name = "LOCATION", value_parser = utils::eval_f64)]
+        x0: f64,
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        g: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ L(m, b)     Laplace distribution
+    Laplace {
+        #[arg(value_name = "LOCATION", value_parser = utils::eval_f64)]
+        m: f64,
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        b: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// X ~ Pa(m, a)    Pareto distribution
+    Pareto {
+        /// smallest possible value
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        m: f64,
+        #[arg(value_name = "SHAPE", value_parser = utils::eval_f64)]
+        a: f64,
+        #[arg(value_name = "KEY_POINTS", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        #[command(flatten)]
+        extras: Extras,
+    },
+
+    /// Reverse-engineer the Binomial distribution
+    Ibinom {
+        #[arg(value_name = "TRIALS", value_parser = utils::eval_u64)]
+        n: u64,
+        #[arg(value_name = "WIN_RATE", value_parser = utils::eval_prob)]
+        p: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        q: f64,
+    },
+
+    /// Reverse-engineer the Negative Binomial distribution
+    Inbinom {
+        #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
+        k: u64,
+        #[arg(value_name = "WIN_RATE", value_parser = utils::eval_prob)]
+        p: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        q: f64,
+    },
+
+    /// Reverse-engineer the Geometric distribution
+    Igeom {
+        #[arg(value_name = "WIN_RATE", value_parser = utils::eval_prob)]
+        p: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        q: f64,
+    },
+
+    /// Reverse-engineer the Poisson distribution
+    Ipois {
+        #[arg(value_name = "EXPECTED", value_parser = utils::eval_f64)]
+        l: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        q: f64,
     },
 
     /// Reverse-engineer the Normal distribution
@@ -181,10 +553,134 @@ enum Commands {
     },
 
     /// Reverse-engineer the Chi-squared distribution
+    #[command(allow_missing_positional = true)]
     Ichisq {
         /// degrees of freedom
         #[arg(value_name = "FREEDOM", value_parser = utils::eval_u64)]
         n: u64,
+        #[arg(value_name = "AREA", value_enum, default_value_t = Area::Right)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Fisher-Snedecor distribution
+    If {
+        #[arg(value_name = "FREEDOM_1", value_parser = utils::eval_u64)]
+        m: u64,
+        #[arg(value_name = "FREEDOM_2", value_parser = utils::eval_u64)]
+        n: u64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Exponential distribution
+    Iexp {
+        #[arg(value_name = "RATE", value_parser = utils::eval_f64)]
+        l: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Uniform distribution
+    Iunif {
+        #[arg(value_name = "MIN", value_parser = utils::eval_f64)]
+        min: f64,
+        #[arg(value_name = "MAX", value_parser = utils::eval_f64)]
+        max: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Gamma distribution
+    Igamma {
+        #[arg(value_name = "SHAPE", value_parser = utils::eval_f64)]
+        a: f64,
+        #[arg(value_name = "RATE", value_parser = utils::eval_f64)]
+        b: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        area: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Beta distribution
+    Ibeta {
+        #[arg(value_name = "SHAPE_A", value_parser = utils::eval_f64)]
+        a: f64,
+        #[arg(value_name = "SHAPE_B", value_parser = utils::eval_f64)]
+        b: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        area: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Log-normal distribution
+    Ilognorm {
+        /// mean of ln(X)
+        #[arg(value_name = "MEAN_LOG", value_parser = utils::eval_f64)]
+        m: f64,
+        /// standard deviation of ln(X)
+        #[arg(value_name = "STD_DEV_LOG", value_parser = utils::eval_f64)]
+        s: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Weibull distribution
+    Iweibull {
+        #[arg(value_name = "SHAPE", value_parser = utils::eval_f64)]
+        k: f64,
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        l: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Cauchy distribution
+    Icauchy {
+        #[arg(value_name = "LOCATION", value_parser = utils::eval_f64)]
+        x0: f64,
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        g: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Laplace distribution
+    Ilaplace {
+        #[arg(value_name = "LOCATION", value_parser = utils::eval_f64)]
+        m: f64,
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        b: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        a: Area,
+        #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// Reverse-engineer the Pareto distribution
+    Ipareto {
+        /// smallest possible value
+        #[arg(value_name = "SCALE", value_parser = utils::eval_f64)]
+        m: f64,
+        #[arg(value_name = "SHAPE", value_parser = utils::eval_f64)]
+        a: f64,
+        #[arg(value_name = "AREA", value_enum)]
+        area: Area,
         #[arg(value_name = "PROBABILITY", value_parser = utils::eval_prob)]
         p: f64,
     },
@@ -222,6 +718,186 @@ enum Commands {
         f2: String,
     },
 
+    /// Five-number summary, outliers and box plot of data from a file
+    #[command(long_about = help::BOX)]
+    Box {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+    },
+
+    /// Histogram of data from a file
+    #[command(long_about = help::HIST)]
+    Hist {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        /// number of bins
+        #[arg(short, long, value_parser = utils::eval_u64)]
+        #[arg(conflicts_with_all = ["width", "rule"])]
+        bins: Option<u64>,
+        /// width of each bin
+        #[arg(short, long, value_parser = utils::eval_f64)]
+        #[arg(conflicts_with = "rule")]
+        width: Option<f64>,
+        /// rule for choosing the bin width
+        #[arg(short, long, value_enum, default_value_t = BinRule::Sturges)]
+        rule: BinRule,
+    },
+
+    /// Stem-and-leaf display of data from a file
+    Stem {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+    },
+
+    /// Perform a t-test on data from files
+    Ttest {
+        #[command(subcommand)]
+        test: TTest,
+    },
+
+    /// Perform a z-test with known population variances
+    Ztest {
+        #[command(subcommand)]
+        test: ZTest,
+        /// region of the alternative hypothesis
+        #[arg(short, long, value_enum, default_value_t = Area::Mid)]
+        #[arg(global = true)]
+        tail: Area,
+        /// significance level
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob, global = true)]
+        alpha: f64,
+    },
+
+    /// Inference for one or two population proportions
+    #[command(long_about = help::PROP)]
+    Prop {
+        #[command(subcommand)]
+        test: PropTest,
+    },
+
+    /// Construct a confidence interval
+    Ci {
+        #[command(subcommand)]
+        interval: Interval,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob, global = true)]
+        alpha: f64,
+    },
+
+    /// Perform an F-test for equality of two variances
+    Ftest {
+        #[arg(value_name = "FILE_1")]
+        f1: String,
+        #[arg(value_name = "FILE_2")]
+        f2: String,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// Chi-squared goodness-of-fit test
+    #[command(long_about = help::GOF)]
+    Gof {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        /// fit a distribution to <value> <observed> pairs
+        #[arg(short, long, value_enum)]
+        fit: Option<Fit>,
+        /// number of trials of a fitted binomial distribution
+        /// (defaults to the largest value)
+        #[arg(short = 'n', long, value_parser = utils::eval_u64)]
+        trials: Option<u64>,
+    },
+
+    /// Chi-squared test of independence
+    #[command(long_about = help::CHISQ_INDEP)]
+    ChisqIndep {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+    },
+
+    /// One-way ANOVA of samples from files
+    #[command(long_about = help::ANOVA)]
+    Anova {
+        #[arg(value_name = "FILES", required = true)]
+        files: Vec<String>,
+        /// read samples from one file of <group> <value> lines
+        #[arg(short, long)]
+        grouped: bool,
+        /// compare each pair of means
+        #[arg(short, long, value_enum)]
+        post: Option<PostHoc>,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// Sign test of the median of data from a file
+    #[command(long_about = help::SIGN)]
+    Sign {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        /// hypothesized median
+        #[arg(value_name = "MEDIAN", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        median: f64,
+        /// test the differences of <x> <y> pairs
+        #[arg(short, long)]
+        paired: bool,
+    },
+
+    /// Wilcoxon signed-rank test of data from a file
+    #[command(long_about = help::WILCOXON)]
+    Wilcoxon {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        /// hypothesized median
+        #[arg(value_name = "MEDIAN", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        median: f64,
+        /// test the differences of <x> <y> pairs
+        #[arg(short, long)]
+        paired: bool,
+    },
+
+    /// Mann-Whitney U test of two samples from files
+    #[command(long_about = help::MANN_WHITNEY)]
+    MannWhitney {
+        #[arg(value_name = "FILE_1")]
+        f1: String,
+        #[arg(value_name = "FILE_2")]
+        f2: String,
+    },
+
+    /// Simple linear regression from a file of x y pairs
+    #[command(long_about = help::REGRESS)]
+    Regress {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        /// x values at which to predict y
+        #[arg(value_name = "X", value_parser = utils::eval_f64)]
+        x: Vec<f64>,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// Correlation of x y pairs from a file
+    #[command(long_about = help::CORR)]
+    Corr {
+        #[arg(value_name = "FILENAME")]
+        file: String,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
     /// Evaluate an expression
     Eval {
         #[arg(value_name = "EXPR")]
@@ -232,35 +908,511 @@ enum Commands {
     Secret,
 }
 
-fn send(v: impl std::fmt::Display) {
-    println!("{}", v);
+#[derive(Subcommand)]
+enum TTest {
+    /// H0: μ = MEAN, from a file of numbers
+    One {
+        #[arg(value_name = "FILE")]
+        file: String,
+        #[arg(value_name = "MEAN", value_parser = utils::eval_f64)]
+        mean: f64,
+        /// significance level
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// H0: μd = MEAN, from a file of number pairs
+    Paired {
+        #[arg(value_name = "FILE")]
+        file: String,
+        #[arg(value_name = "MEAN", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        mean: f64,
+        /// significance level
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// H0: μ1 - μ2 = DIFF, assuming equal variances
+    Pooled {
+        #[arg(value_name = "FILE_1")]
+        f1: String,
+        #[arg(value_name = "FILE_2")]
+        f2: String,
+        #[arg(value_name = "DIFF", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        diff: f64,
+        /// significance level
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// H0: μ1 - μ2 = DIFF, without assuming equal variances
+    Welch {
+        #[arg(value_name = "FILE_1")]
+        f1: String,
+        #[arg(value_name = "FILE_2")]
+        f2: String,
+        #[arg(value_name = "DIFF", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        diff: f64,
+        /// significance level
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
 }
 
-fn process<T>(data: impl Analyze<T>, x: &Vec<T>) {
-    let mut analysis = data.analyze(x);
-    analysis.round();
-    send(analysis);
+#[derive(Subcommand)]
+enum ZTest {
+    /// H0: μ = MEAN, from summary statistics
+    One {
+        #[arg(value_name = "SAMPLE_MEAN", value_parser = utils::eval_f64)]
+        m: f64,
+        #[arg(value_name = "STD_DEV", value_parser = utils::eval_f64)]
+        s: f64,
+        #[arg(value_name = "SIZE", value_parser = utils::eval_u64)]
+        n: u64,
+        #[arg(value_name = "MEAN", value_parser = utils::eval_f64)]
+        mean: f64,
+    },
+
+    /// H0: μ1 - μ2 = DIFF, from summary statistics
+    Two {
+        #[arg(value_name = "SAMPLE_MEAN_1", value_parser = utils::eval_f64)]
+        m1: f64,
+        #[arg(value_name = "STD_DEV_1", value_parser = utils::eval_f64)]
+        s1: f64,
+        #[arg(value_name = "SIZE_1", value_parser = utils::eval_u64)]
+        n1: u64,
+        #[arg(value_name = "SAMPLE_MEAN_2", value_parser = utils::eval_f64)]
+        m2: f64,
+        #[arg(value_name = "STD_DEV_2", value_parser = utils::eval_f64)]
+        s2: f64,
+        #[arg(value_name = "SIZE_2", value_parser = utils::eval_u64)]
+        n2: u64,
+        #[arg(value_name = "DIFF", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        diff: f64,
+    },
+
+    /// H0: μ = MEAN, from a file of numbers
+    OneFile {
+        #[arg(value_name = "FILE")]
+        file: String,
+        #[arg(value_name = "STD_DEV", value_parser = utils::eval_f64)]
+        s: f64,
+        #[arg(value_name = "MEAN", value_parser = utils::eval_f64)]
+        mean: f64,
+    },
+
+    /// H0: μ1 - μ2 = DIFF, from two files of numbers
+    TwoFile {
+        #[arg(value_name = "FILE_1")]
+        f1: String,
+        #[arg(value_name = "STD_DEV_1", value_parser = utils::eval_f64)]
+        s1: f64,
+        #[arg(value_name = "FILE_2")]
+        f2: String,
+        #[arg(value_name = "STD_DEV_2", value_parser = utils::eval_f64)]
+        s2: f64,
+        #[arg(value_name = "DIFF", default_value_t = 0.0)]
+        #[arg(value_parser = utils::eval_f64)]
+        diff: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum PropTest {
+    /// H0: p = P, from a count of wins
+    One {
+        #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
+        x: u64,
+        #[arg(value_name = "TRIALS", value_parser = utils::eval_u64)]
+        n: u64,
+        #[arg(value_name = "P", default_value_t = 0.5)]
+        #[arg(value_parser = utils::eval_prob)]
+        p: f64,
+    },
+
+    /// H0: p1 = p2, from counts of wins
+    Two {
+        #[arg(value_name = "WINS_1", value_parser = utils::eval_u64)]
+        x1: u64,
+        #[arg(value_name = "TRIALS_1", value_parser = utils::eval_u64)]
+        n1: u64,
+        #[arg(value_name = "WINS_2", value_parser = utils::eval_u64)]
+        x2: u64,
+        #[arg(value_name = "TRIALS_2", value_parser = utils::eval_u64)]
+        n2: u64,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+
+    /// Wald, Wilson and Clopper-Pearson intervals for p
+    Ci {
+        #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
+        x: u64,
+        #[arg(value_name = "TRIALS", value_parser = utils::eval_u64)]
+        n: u64,
+        /// significance level (confidence level is 1 - alpha)
+        #[arg(short, long, default_value_t = 0.05)]
+        #[arg(value_parser = utils::eval_prob)]
+        alpha: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum Interval {
+    /// Population mean, from a file of numbers
+    Mean {
+        #[arg(value_name = "FILE")]
+        file: String,
+        /// known population standard deviation (uses z instead of t)
+        #[arg(short, long, value_parser = utils::eval_f64)]
+        sigma: Option<f64>,
+    },
+
+    /// Difference of population means, from two files of numbers
+    Diff {
+        #[arg(value_name = "FILE_1")]
+        f1: String,
+        #[arg(value_name = "FILE_2")]
+        f2: String,
+        /// do not assume equal population variances
+        #[arg(short, long)]
+        welch: bool,
+    },
+
+    /// Population proportion, from a count of wins
+    Prop {
+        #[arg(value_name = "WINS", value_parser = utils::eval_u64)]
+        x: u64,
+        #[arg(value_name = "TRIALS", value_parser = utils::eval_u64)]
+        n: u64,
+    },
+
+    /// Population variance, from a file of numbers
+    Var {
+        #[arg(value_name = "FILE")]
+        file: String,
+    },
+}
+
+fn process<T>(
+    data: impl distributions::Distribution + Analyze<T> + Plot<T> + Expect<T>,
+    x: &[T],
+    extras: Extras,
+) -> Result<LineList> {
+    process_with(data, x, LineList::new(), extras)
+}
+
+/// process() for a discrete distribution, with an optional comparison
+/// with an approximating distribution
+fn discrete<D: distributions::Distribution + distributions::Discrete>(
+    dist: D,
+    x: &[u64],
+    approx: Option<Approx>,
+    extras: Extras,
+) -> Result<LineList> {
+    let more = match approx {
+        Some(Approx::Normal) => analyze::normal_approximation(&dist, x)?,
+        None => LineList::new(),
+    };
+    process_with(dist, x, more, extras)
+}
+
+/// the analysis, with any moments, followed by `more`, then any
+/// E[g(X)], and then any plot
+fn process_with<T, D>(
+    dist: D,
+    x: &[T],
+    more: LineList,
+    extras: Extras,
+) -> Result<LineList>
+where
+    D: distributions::Distribution + Analyze<T> + Plot<T> + Expect<T>,
+{
+    let mut analysis = dist.analyze(x);
+    if extras.moments {
+        analysis.moments = analyze::moments(&dist);
+    }
+    let mut list: LineList = analysis.into();
+    list.append(&more);
+    if let Some(g) = extras.expect {
+        list.append(&dist.expect(&g)?);
+    }
+    if extras.plot {
+        list.list.extend(dist.plot(x));
+    }
+    Ok(list)
+}
+
+fn ttest(test: TTest) -> Result<LineList> {
+    use data_set::Parser::{PairDiff, Single};
+    use hypothesis::TTest as T;
+    let read = data_set::analyze;
+    match test {
+        TTest::One { file, mean, alpha } => {
+            T::one_sample(&mut read(&file, Single)?, mean)?.export(alpha)
+        }
+        TTest::Paired { file, mean, alpha } => {
+            T::paired(&mut read(&file, PairDiff)?, mean)?.export(alpha)
+        }
+        TTest::Pooled { f1, f2, diff, alpha } => {
+            let (mut d1, mut d2) = (read(&f1, Single)?, read(&f2, Single)?);
+            T::pooled(&mut d1, &mut d2, diff)?.export(alpha)
+        }
+        TTest::Welch { f1, f2, diff, alpha } => {
+            let (mut d1, mut d2) = (read(&f1, Single)?, read(&f2, Single)?);
+            T::welch(&mut d1, &mut d2, diff)?.export(alpha)
+        }
+    }
+}
+
+/// mean and size of the sample in a file, for tests with a known σ
+fn sample_mean(file: &str) -> Result<(f64, f64)> {
+    let v = data_set::sample(file, data_set::Parser::Single)?;
+    let n = v.len() as f64;
+    Ok((v.iter().sum::<f64>() / n, n))
+}
+
+fn ztest(test: ZTest) -> Result<hypothesis::ZTest> {
+    use hypothesis::ZTest as Z;
+    match test {
+        ZTest::One { m, s, n, mean } => Z::one_sample(m, s, n as f64, mean),
+        ZTest::Two { m1, s1, n1, m2, s2, n2, diff } => {
+            Z::two_sample((m1, s1, n1 as f64), (m2, s2, n2 as f64), diff)
+        }
+        ZTest::OneFile { file, s, mean } => {
+            let (m, n) = sample_mean(&file)?;
+            Z::one_sample(m, s, n, mean)
+        }
+        ZTest::TwoFile { f1, s1, f2, s2, diff } => {
+            let ((m1, n1), (m2, n2)) = (sample_mean(&f1)?, sample_mean(&f2)?);
+            Z::two_sample((m1, s1, n1), (m2, s2, n2), diff)
+        }
+    }
+}
+
+fn interval(interval: Interval, alpha: f64) -> Result<LineList> {
+    use hypothesis::{TTest, ZTest};
+    let read = |f: &str| data_set::analyze(f, data_set::Parser::Single);
+    let (title, mut list) = match interval {
+        Interval::Mean { file, sigma: Some(s) } => {
+            let (m, n) = sample_mean(&file)?;
+            let test = ZTest::one_sample(m, s, n, 0.0)?;
+            ("CI for μ (σ known)", test.interval(alpha)?)
+        }
+        Interval::Mean { file, sigma: None } => {
+            let test = TTest::one_sample(&mut read(&file)?, 0.0)?;
+            ("CI for μ (σ unknown)", test.interval(alpha)?)
+        }
+        Interval::Diff { f1, f2, welch } => {
+            let (mut d1, mut d2) = (read(&f1)?, read(&f2)?);
+            match welch {
+                true => {
+                    let test = TTest::welch(&mut d1, &mut d2, 0.0)?;
+                    ("CI for μ1 - μ2 (Welch)", test.interval(alpha)?)
+                }
+                false => {
+                    let test = TTest::pooled(&mut d1, &mut d2, 0.0)?;
+                    ("CI for μ1 - μ2 (pooled)", test.interval(alpha)?)
+                }
+            }
+        }
+        Interval::Prop { x, n } => return confidence::proportion(x, n, alpha),
+        Interval::Var { file } => {
+            return confidence::variance(&mut read(&file)?, alpha)
+        }
+    };
+    list.set_title(title);
+    Ok(list)
+}
+
+fn gof(file: &str, fit: Option<Fit>, trials: Option<u64>) -> Result<LineList> {
+    use distributions::{Binomial, Poisson};
+    use hypothesis::ChiSquaredTest as Chi;
+    let rows = data_set::table(file)?;
+    let test = match fit {
+        None => Chi::goodness_of_fit(&rows)?,
+        Some(Fit::Pois) => Chi::fit(&rows, Poisson::new)?,
+        Some(Fit::Binom) => {
+            let largest = rows.iter().map(|v| v[0]).fold(0.0, f64::max);
+            let n = trials.unwrap_or(largest as u64);
+            Chi::fit(&rows, |mean| Binomial::new(n, mean / n as f64))?
+        }
+    };
+    test.export()
+}
+
+fn hist(
+    file: &str,
+    bins: Option<u64>,
+    width: Option<f64>,
+    rule: BinRule,
+) -> Result<LineList> {
+    use data_set::Bins;
+    let bins = match (bins, width, rule) {
+        (Some(0), _, _) => return utils::err("at least one bin is needed."),
+        (Some(k), _, _) => Bins::Count(k),
+        (_, Some(w), _) => Bins::Width(w),
+        (_, _, BinRule::Sturges) => Bins::Sturges,
+        (_, _, BinRule::Scott) => Bins::Scott,
+        (_, _, BinRule::Fd) => Bins::FreedmanDiaconis,
+    };
+    data_set::analyze(file, data_set::Parser::Single)?.histogram(bins)
+}
+
+fn anova(files: &[String], grouped: bool) -> Result<hypothesis::Anova> {
+    use data_set::Parser::Single;
+    let samples = match (grouped, files) {
+        (true, [file]) => data_set::groups(file)?,
+        (true, _) => return utils::err("--grouped reads exactly one file."),
+        (false, files) => files
+            .iter()
+            .map(|f| Ok((f.to_string(), data_set::sample(f, Single)?)))
+            .collect::<Result<Vec<_>>>()?,
+    };
+    hypothesis::Anova::new(&samples)
+}
+
+/// one sample of values, or the differences of <x> <y> pairs
+fn one_sample(file: &str, paired: bool) -> Result<Vec<f64>> {
+    use data_set::Parser::{PairDiff, Single};
+    data_set::sample(file, if paired { PairDiff } else { Single })
 }
 
 fn run(cli: Cli) -> Result<()> {
     use distributions::*;
-    use Area::*;
+    let precision = match cli.sig_figs {
+        Some(n) => math::Precision::SigFigs(n),
+        None => math::Precision::Decimals(cli.precision),
+    };
+    let send =
+        |list: LineList| println!("{}", list.render(cli.format, precision));
     match cli.command {
-        Commands::Binom { n, p, x } => process(Binomial::new(n, p)?, &x),
-        Commands::Nbinom { k, p, x } => {
-            process(NegativeBinomial::new(k, p)?, &x)
-        }
-        Commands::Geom { p, x } => process(Geometric::new(p)?, &x),
-        Commands::Pois { l, x } => process(Poisson::new(l)?, &x),
-        Commands::Unif { a, b, x } => process(Uniform::new(a, b)?, &x),
-        Commands::Exp { l, x } => process(Exponential::new(l)?, &x),
-        Commands::Norm { m, s, x } => process(Normal::new(m, s)?, &x),
-        Commands::T { f, x } => process(StudentsT::new(f)?, &x),
-        Commands::Chisq { n, x } => process(ChiSquared::new(n)?, &x),
-        Commands::F { m, n, x } => process(FisherSnedecor::new(m, n)?, &x),
-        Commands::Ichisq { n, p } => send(ChiSquared::new(n)?.invert(Right, p)),
+        Commands::Binom { n, p, x, approx, extras } => {
+            send(discrete(Binomial::new(n, p)?, &x, approx, extras)?)
+        }
+        Commands::Nbinom { k, p, x, approx, extras } => {
+            send(discrete(NegativeBinomial::new(k, p)?, &x, approx, extras)?)
+        }
+        Commands::Geom { p, x, approx, extras } => {
+            send(discrete(Geometric::new(p)?, &x, approx, extras)?)
+        }
+        Commands::Pois { l, x, approx, extras } => {
+            send(discrete(Poisson::new(l)?, &x, approx, extras)?)
+        }
+        Commands::Hypergeom { m, k, n, x, approx, extras } => {
+            send(discrete(Hypergeometric::new(m, k, n)?, &x, approx, extras)?)
+        }
+        Commands::Dunif { a, b, x, approx, extras } => {
+            send(discrete(DiscreteUniform::new(a, b)?, &x, approx, extras)?)
+        }
+        Commands::Bernoulli { p, x, approx, extras } => {
+            send(discrete(Bernoulli::new(p)?, &x, approx, extras)?)
+        }
+        Commands::Categorical { p, x, approx, extras } => {
+            send(discrete(Categorical::new(&p)?, &x, approx, extras)?)
+        }
+        Commands::Multinom { p, x, trials } => {
+            let x = x.unwrap_or_default();
+            let n = match trials {
+                Some(n) => n,
+                None if !x.is_empty() => x.iter().sum(),
+                None => return utils::err("Give the counts or --trials."),
+            };
+            send(Multinomial::new(&p, n)?.analyze(&x)?)
+        }
+        Commands::Pmf { file, x, approx, extras } => {
+            let data = data_set::analyze(&file, data_set::Parser::Single)?;
+            let dist = Tabulated::new(&file, &data.distribution())?;
+            send(discrete(dist, &x, approx, extras)?)
+        }
+        Commands::Unif { a, b, x, extras } => {
+            send(process(Uniform::new(a, b)?, &x, extras)?)
+        }
+        Commands::Exp { l, x, extras } => {
+            send(process(Exponential::new(l)?, &x, extras)?)
+        }
+        Commands::Norm { m, s, x, extras } => {
+            send(process(Normal::new(m, s)?, &x, extras)?)
+        }
+        Commands::T { f, x, extras } => {
+            send(process(StudentsT::new(f)?, &x, extras)?)
+        }
+        Commands::Chisq { n, x, extras } => {
+            send(process(ChiSquared::new(n)?, &x, extras)?)
+        }
+        Commands::F { m, n, x, extras } => {
+            send(process(FisherSnedecor::new(m, n)?, &x, extras)?)
+        }
+        Commands::Gamma { a, b, x, extras } => {
+            send(process(Gamma::new(a, b)?, &x, extras)?)
+        }
+        Commands::Beta { a, b, x, extras } => {
+            send(process(Beta::new(a, b)?, &x, extras)?)
+        }
+        Commands::Lognorm { m, s, x, extras } => {
+            send(process(LogNormal::new(m, s)?, &x, extras)?)
+        }
+        Commands::Weibull { k, l, x, extras } => {
+            send(process(Weibull::new(k, l)?, &x, extras)?)
+        }
+        Commands::Cauchy { x0, g, x, extras } => {
+            send(process(Cauchy::new(x0, g)?, &x, extras)?)
+        }
+        Commands::Laplace { m, b, x, extras } => {
+            send(process(Laplace::new(m, b)?, &x, extras)?)
+        }
+        Commands::Pareto { m, a, x, extras } => {
+            send(process(Pareto::new(m, a)?, &x, extras)?)
+        }
+        Commands::Ibinom { n, p, a, q } => {
+            send(Binomial::new(n, p)?.invert(a, q))
+        }
+        Commands::Inbinom { k, p, a, q } => {
+            send(NegativeBinomial::new(k, p)?.invert(a, q))
+        }
+        Commands::Igeom { p, a, q } => send(Geometric::new(p)?.invert(a, q)),
+        Commands::Ipois { l, a, q } => send(Poisson::new(l)?.invert(a, q)),
+        Commands::Ichisq { n, a, p } => send(ChiSquared::new(n)?.invert(a, p)),
         Commands::Inorm { a, m, s, p } => send(Normal::new(m, s)?.invert(a, p)),
         Commands::It { a, f, p } => send(StudentsT::new(f)?.invert(a, p)),
+        Commands::If { m, n, a, p } => {
+            send(FisherSnedecor::new(m, n)?.invert(a, p))
+        }
+        Commands::Iexp { l, a, p } => send(Exponential::new(l)?.invert(a, p)),
+        Commands::Iunif { min, max, a, p } => {
+            send(Uniform::new(min, max)?.invert(a, p))
+        }
+        Commands::Igamma { a, b, area, p } => {
+            send(Gamma::new(a, b)?.invert(area, p))
+        }
+        Commands::Ibeta { a, b, area, p } => {
+            send(Beta::new(a, b)?.invert(area, p))
+        }
+        Commands::Ilognorm { m, s, a, p } => {
+            send(LogNormal::new(m, s)?.invert(a, p))
+        }
+        Commands::Iweibull { k, l, a, p } => {
+            send(Weibull::new(k, l)?.invert(a, p))
+        }
+        Commands::Icauchy { x0, g, a, p } => {
+            send(Cauchy::new(x0, g)?.invert(a, p))
+        }
+        Commands::Ilaplace { m, b, a, p } => {
+            send(Laplace::new(m, b)?.invert(a, p))
+        }
+        Commands::Ipareto { m, a, area, p } => {
+            send(Pareto::new(m, a)?.invert(area, p))
+        }
         Commands::Vpool { v1, v2, n1, n2 } => {
             send(math::pooled_variance(n1 as f64, v1, n2 as f64, v2))
         }
@@ -287,10 +1439,67 @@ fn run(cli: Cli) -> Result<()> {
         Commands::Diff { file } => {
             send(data_set::analyze(&file, data_set::Parser::PairDiff)?.export())
         }
-        Commands::Eval { expr } => match utils::eval_f64(&expr.join(" ")) {
-            Ok(v) => send(v),
-            Err(_) => send("Invalid expression."),
-        },
+        Commands::Box { file } => {
+            send(data_set::analyze(&file, data_set::Parser::Single)?.boxplot())
+        }
+        Commands::Hist { file, bins, width, rule } => {
+            send(hist(&file, bins, width, rule)?)
+        }
+        Commands::Stem { file } => {
+            send(data_set::analyze(&file, data_set::Parser::Single)?.stem()?)
+        }
+        Commands::Ttest { test } => send(ttest(test)?),
+        Commands::Ztest { test, tail, alpha } => {
+            send(ztest(test)?.export(tail, alpha)?)
+        }
+        Commands::Ci { interval: i, alpha } => send(interval(i, alpha)?),
+        Commands::Prop { test: PropTest::One { x, n, p } } => {
+            send(hypothesis::one_proportion(x, n, p)?)
+        }
+        Commands::Prop { test: PropTest::Two { x1, n1, x2, n2, alpha } } => {
+            send(hypothesis::two_proportions((x1, n1), (x2, n2), alpha)?)
+        }
+        Commands::Prop { test: PropTest::Ci { x, n, alpha } } => {
+            send(confidence::proportion_intervals(x, n, alpha)?)
+        }
+        Commands::Ftest { f1, f2, alpha } => {
+            let mut d1 = data_set::analyze(&f1, data_set::Parser::Single)?;
+            let mut d2 = data_set::analyze(&f2, data_set::Parser::Single)?;
+            send(hypothesis::FTest::new(&mut d1, &mut d2)?.export(alpha)?)
+        }
+        Commands::Gof { file, fit, trials } => send(gof(&file, fit, trials)?),
+        Commands::ChisqIndep { file } => {
+            let rows = data_set::table(&file)?;
+            send(hypothesis::ChiSquaredTest::independence(&rows)?.export()?)
+        }
+        Commands::Anova { files, grouped, post, alpha } => {
+            send(anova(&files, grouped)?.export(post, alpha)?)
+        }
+        Commands::Sign { file, median, paired } => {
+            let title = format!("H0: median = {median}");
+            let values = one_sample(&file, paired)?;
+            send(hypothesis::sign_test(&values, median, &title)?)
+        }
+        Commands::Wilcoxon { file, median, paired } => {
+            let title = format!("H0: symmetric about median = {median}");
+            let values = one_sample(&file, paired)?;
+            send(hypothesis::signed_rank(&values, median, &title)?)
+        }
+        Commands::MannWhitney { f1, f2 } => {
+            let sample = |f| data_set::sample(f, data_set::Parser::Single);
+            let (x, y) = (sample(&f1)?, sample(&f2)?);
+            send(hypothesis::mann_whitney(&x, &y)?)
+        }
+        Commands::Regress { file, x, alpha } => {
+            let fit = regression::Regression::new(&data_set::pairs(&file)?)?;
+            send(fit.export(alpha, &x)?)
+        }
+        Commands::Corr { file, alpha } => {
+            send(correlation::correlation(&data_set::pairs(&file)?, alpha)?)
+        }
+        Commands::Eval { expr } => {
+            send(LineList::value(utils::eval_f64(&expr.join(" "))?))
+        }
         _ => println!("{}", secret::rot13(secret::SECRET.trim())),
     }
     Ok(())
@@ -298,7 +1507,13 @@ fn run(cli: Cli) -> Result<()> {
 
 fn main() {
     let cli = Cli::parse();
-    run(cli).map_err(|v| println!("{}", v)).ok();
+    let format = cli.format;
+    if let Err(e) = run(cli) {
+        match format {
+            Format::Json => println!("{}", printer::json_error(&e)),
+            _ => println!("{}", e),
+        }
+    }
 }
 
 #[test]
@@ -314,7 +1529,9 @@ fn subcommand_coverage() -> Result<()> {
         .get_subcommands()
         .map(|v| v.get_name().to_string())
         .filter(|v| !v.eq("secret"))
-        .map(|v| (format!("integration::{}_test: test", v), v))
+        .map(|v| {
+            (format!("integration::{}_test: test", v.replace('-', "_")), v)
+        })
         .collect::<HashMap<_, _>>();
 
     // list of existing tests
@@ -323,7 +1540,7 @@ fn subcommand_coverage() -> Result<()> {
     let mut cargo = cargo.stdout(Stdio::piped()).spawn().serr("bopes")?;
     BufReader::new(cargo.stdout.as_mut().ok_or("bopes")?)
         .lines()
-        .filter_map(|v| v.ok())
+        .map_while(|v| v.ok())
         .for_each(|t| {
             subcommands.remove(&t);
         });
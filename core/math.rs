@@ -1,4 +1,6 @@
 use crate::display::LineList;
+use statrs::function::factorial::ln_factorial;
+use std::f64::consts::TAU;
 
 trait MathOps {
     fn pow(self, exponent: u64) -> Self;
@@ -19,18 +21,91 @@ pub trait Round {
 }
 
 impl Round for f64 {
+    /// values too large to hold that many decimals are left as they are,
+    /// as shifting them back would only add rounding error
     fn roundn(&self, decimals: u64) -> f64 {
         let shift = 10.pow(decimals) as f64;
         let res = self * shift;
+        if res.abs() >= 2f64.powi(52) {
+            return *self;
+        }
         let res = res.round();
-        let res = res / shift;
-        res
+        res / shift
+    }
+}
+
+/// How numbers are shown to humans. Values that would otherwise
+/// lose their magnitude are shown in scientific notation.
+#[derive(Clone, Copy)]
+pub enum Precision {
+    /// round to this many decimal places
+    Decimals(u64),
+    /// round to this many significant figures
+    SigFigs(u64),
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision::Decimals(10)
     }
 }
 
+/// scientific notation with `digits` digits after the decimal point,
+/// without trailing zeros
+fn scientific(v: f64, digits: usize) -> String {
+    let s = format!("{:.*e}", digits, v);
+    match s.split_once('e') {
+        Some((m, e)) if m.contains('.') => {
+            format!("{}e{}", m.trim_end_matches('0').trim_end_matches('.'), e)
+        }
+        _ => s,
+    }
+}
+
+/// round v to n significant figures, where e is the exponent of v
+fn round_sig(v: f64, n: u64, e: i32) -> f64 {
+    let k = n as i32 - 1 - e;
+    match k >= 0 {
+        true => (v * 10f64.powi(k)).round() / 10f64.powi(k),
+        false => (v / 10f64.powi(-k)).round() * 10f64.powi(-k),
+    }
+}
+
+impl Precision {
+    pub fn format(&self, v: f64) -> String {
+        if v == 0.0 || !v.is_finite() {
+            return v.to_string();
+        }
+        let e = v.abs().log10().floor() as i32;
+        match *self {
+            Precision::Decimals(d) => match v.roundn(d) {
+                r if r == 0.0 || e >= 16 => scientific(v, d as usize),
+                r => r.to_string(),
+            },
+            Precision::SigFigs(n) => match !(-6..16).contains(&e) {
+                true => scientific(v, n as usize - 1),
+                false => round_sig(v, n, e).to_string(),
+            },
+        }
+    }
+}
+
+/// whether v, a variance of values whose mean square is `square`, is
+/// only rounding error, as when equal values have a mean that floats
+/// cannot hold exactly
+pub fn no_variance(v: f64, square: f64) -> bool {
+    v <= 1e-20 * square
+}
+
+/// pooled sample variance of two samples with sizes n1, n2
+/// and sample variances v1, v2
+pub fn pool(n1: f64, v1: f64, n2: f64, v2: f64) -> f64 {
+    ((n1 - 1.0) * v1 + (n2 - 1.0) * v2) / (n1 + n2 - 2.0)
+}
+
 pub fn pooled_variance(n1: f64, v1: f64, n2: f64, v2: f64) -> LineList {
     let mut plist = LineList::new();
-    let p = ((n1 - 1.0) * v1 + (n2 - 1.0) * v2) / (n1 + n2 - 2.0);
+    let p = pool(n1, v1, n2, v2);
     plist.push("[1] sample size", n1);
     plist.push("[1] sample variance", v1);
     plist.push("[2] sample size", n2);
@@ -39,3 +114,115 @@ pub fn pooled_variance(n1: f64, v1: f64, n2: f64, v2: f64) -> LineList {
     plist.push("pooled sample std.dev", p.sqrt());
     plist
 }
+
+/// ranks of values from 1 to n, where tied values share the mean
+/// of the ranks they span
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<_> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && values[order[j + 1]] == values[order[i]] {
+            j += 1;
+        }
+        // positions i..=j hold ranks i + 1..=j + 1
+        let rank = (i + j) as f64 / 2.0 + 1.0;
+        order[i..=j].iter().for_each(|k| ranks[*k] = rank);
+        i = j + 1;
+    }
+    ranks
+}
+
+/// ln(n!) - ln{√(2πn)(n/e)ⁿ}, the error in Stirling's approximation
+pub fn stirling_error(n: u64) -> f64 {
+    let x = n as f64;
+    if n <= 15 {
+        return ln_factorial(n) - (x + 0.5) * x.ln() + x - (TAU.sqrt()).ln();
+    }
+    let xx = x * x;
+    (1.0 / 12.0
+        - (1.0 / 360.0
+            - (1.0 / 1260.0 - (1.0 / 1680.0 - 1.0 / 1188.0 / xx) / xx) / xx)
+            / xx)
+        / x
+}
+
+/// x ln(x/m) + m - x, by its series in (x - m)/(x + m) when x is near m,
+/// where it would otherwise cancel
+pub fn deviance(x: f64, m: f64) -> f64 {
+    if (x - m).abs() >= 0.1 * (x + m) {
+        return x * (x / m).ln() + m - x;
+    }
+    let v = (x - m) / (x + m);
+    let (mut total, mut term) = ((x - m) * v, 2.0 * x * v);
+    for j in 1.. {
+        term *= v * v;
+        let next = total + term / (2 * j + 1) as f64;
+        if next == total {
+            break;
+        }
+        total = next;
+    }
+    total
+}
+
+/// P(X = x) for X ~ B(n, p), by Loader's saddle point expansion, which
+/// keeps full precision for large n, where differences of log gamma
+/// functions, as statrs takes, lose it
+pub fn binomial_pmf(x: u64, n: u64, p: f64) -> f64 {
+    let q = 1.0 - p;
+    match x {
+        _ if x > n => 0.0,
+        _ if x == n => p.powf(n as f64),
+        0 => (n as f64 * (-p).ln_1p()).exp(),
+        _ => {
+            let (nf, xf, yf) = (n as f64, x as f64, (n - x) as f64);
+            let lc = stirling_error(n)
+                - stirling_error(x)
+                - stirling_error(n - x)
+                - deviance(xf, nf * p)
+                - deviance(yf, nf * q);
+            lc.exp() * (nf / (TAU * xf * yf)).sqrt()
+        }
+    }
+}
+
+/// Simpson's rule over [a, b] with an even number of intervals
+pub fn simpson<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    intervals: usize,
+) -> f64 {
+    let h = (b - a) / intervals as f64;
+    let inner: f64 = (1..intervals)
+        .map(|i| f(a + i as f64 * h) * if i % 2 == 1 { 4.0 } else { 2.0 })
+        .sum();
+    (f(a) + inner + f(b)) * h / 3.0
+}
+
+#[test]
+fn test() {
+    assert_eq!(ranks(&[30.0, 10.0, 20.0, 10.0]), vec![4.0, 1.5, 3.0, 1.5]);
+    float_eq!(simpson(|x| x * x, 0.0, 3.0, 2), 9.0);
+
+    let p = Precision::default();
+    assert_eq!(p.format(0.0), "0");
+    assert_eq!(p.format(1.6000000000000003), "1.6");
+    assert_eq!(p.format(0.0000000538), "0.0000000538");
+    assert_eq!(p.format(1.23e-14), "1.23e-14");
+    assert_eq!(p.format(-1.23e-14), "-1.23e-14");
+    assert_eq!(p.format(2.5e20), "2.5e20");
+
+    let p = Precision::Decimals(2);
+    assert_eq!(p.format(1.23456), "1.23");
+    assert_eq!(p.format(0.001234), "1.23e-3");
+
+    let p = Precision::SigFigs(3);
+    assert_eq!(p.format(1.23456), "1.23");
+    assert_eq!(p.format(123456.0), "123000");
+    assert_eq!(p.format(0.00012345), "0.000123");
+    assert_eq!(p.format(1.23456e-9), "1.23e-9");
+}
@@ -0,0 +1,189 @@
+//! Text plots, drawn one row at a time so that each row can be shown
+//! as a line of a LineList, aligned with the values around it.
+use crate::display::Line;
+use crate::distributions::{Continuous, Discrete, Distribution};
+use crate::inverse::InvertDiscrete;
+use crate::math::Round;
+use std::fmt::Display;
+
+/// default number of columns of a plot
+pub const WIDTH: usize = 50;
+
+/// most rows in a plot of a distribution
+const ROWS: usize = 25;
+
+/// probability left out of each tail of a plot of a distribution
+const TAIL: f64 = 0.001;
+
+/// probability left out of each tail instead, where leaving out TAIL
+/// spans over 3 times as much, as with the Cauchy distribution, whose
+/// peak would otherwise fill a single row
+const HEAVY_TAIL: f64 = 0.01;
+
+/// maps values in [lo, hi] onto the columns of a plot
+pub struct Scale {
+    lo: f64,
+    hi: f64,
+    width: usize,
+}
+
+impl Scale {
+    pub fn new(lo: f64, hi: f64, width: usize) -> Self {
+        Self { lo, hi, width: width.max(1) }
+    }
+
+    /// column of the value x, clamped to the plot
+    pub fn col(&self, x: f64) -> usize {
+        let last = self.width - 1;
+        if self.hi <= self.lo {
+            return last / 2;
+        }
+        let t = (x - self.lo) / (self.hi - self.lo);
+        (t * last as f64).round().clamp(0.0, last as f64) as usize
+    }
+}
+
+/// draws a single-row box plot:
+/// ├── whiskers ──[══ box │ median ══]──┤   ∘ outliers
+pub fn boxplot(
+    scale: &Scale,
+    whiskers: (f64, f64),
+    quartiles: (f64, f64, f64),
+    outliers: &[f64],
+) -> String {
+    let mut row = vec![' '; scale.width];
+    let (q1, q2, q3) = quartiles;
+    let (w1, w2) = (scale.col(whiskers.0), scale.col(whiskers.1));
+    let (b1, b2) = (scale.col(q1), scale.col(q3));
+    (w1..=w2).for_each(|i| row[i] = '─');
+    (b1..=b2).for_each(|i| row[i] = '═');
+    row[w1] = '├';
+    row[w2] = '┤';
+    row[b1] = '[';
+    row[b2] = ']';
+    row[scale.col(q2)] = '│';
+    outliers.iter().for_each(|v| row[scale.col(*v)] = '∘');
+    row.iter().collect::<String>().trim_end().to_string()
+}
+
+/// a horizontal bar filling `frac` of the width, in eighths of a column
+pub fn bar(frac: f64, width: usize) -> String {
+    const PARTS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let eighths = (frac.clamp(0.0, 1.0) * width as f64 * 8.0).round() as usize;
+    let (full, part) = (eighths / 8, eighths % 8);
+    let mut bar = "█".repeat(full);
+    if part > 0 {
+        bar.push(PARTS[part - 1]);
+    }
+    bar
+}
+
+/// A bar shaded by the region it lies in. Regions alternate between
+/// solid and light shading.
+fn shaded(frac: f64, region: usize) -> String {
+    match region % 2 {
+        0 => bar(frac, WIDTH),
+        _ => "░".repeat((frac.clamp(0.0, 1.0) * WIDTH as f64).round() as usize),
+    }
+}
+
+/// index of the interval of cdf_intervals() that x lies in
+fn region<T: PartialOrd>(x: &T, points: &[T]) -> usize {
+    points.iter().filter(|p| x > *p).count()
+}
+
+/// One row per x, with a bar for its density shaded by region. Rows
+/// are described as "x = ..." so that they do not clash with the
+/// P(X = x) and pdf @ x values in JSON and CSV output.
+fn rows<T: Display + Copy + PartialOrd, F: Fn(T) -> f64>(
+    xs: &[T],
+    points: &[T],
+    density: F,
+) -> Vec<Line> {
+    let ys: Vec<_> = xs.iter().map(|x| density(*x)).collect();
+    let most = ys.iter().cloned().filter(|v| v.is_finite()).fold(0.0, f64::max);
+    let frac = |y: f64| if most > 0.0 { y / most } else { 0.0 };
+    xs.iter()
+        .zip(ys)
+        .map(|(x, y)| {
+            let bar = shaded(frac(y), region(x, points));
+            Line::text(&format!("x = {x}"), &bar)
+        })
+        .collect()
+}
+
+/// Draws the p.m.f. or p.d.f. sideways, one row per value of x, with
+/// the intervals between the given points shaded alternately.
+pub trait Plot<T> {
+    fn plot(&self, points: &[T]) -> Vec<Line>;
+}
+
+impl<D: Distribution + Discrete> Plot<u64> for D {
+    fn plot(&self, points: &[u64]) -> Vec<Line> {
+        let lo = points.iter().fold(self.quantile(TAIL), |a, b| a.min(*b));
+        let hi =
+            points.iter().fold(self.quantile(1.0 - TAIL), |a, b| a.max(*b));
+        let stride = (hi - lo) as usize / ROWS + 1;
+        // the given points are always shown, even between strides
+        let mut xs: Vec<_> =
+            (lo..=hi).step_by(stride).chain(points.iter().cloned()).collect();
+        xs.sort();
+        xs.dedup();
+        rows(&xs, points, |x| self.pmf(x))
+    }
+}
+
+impl<D: Distribution + Continuous> Plot<f64> for D {
+    fn plot(&self, points: &[f64]) -> Vec<Line> {
+        let range = |tail: f64| (self.inv_cdf(tail), self.inv_cdf(1.0 - tail));
+        let (lo, hi) = match (range(TAIL), range(HEAVY_TAIL)) {
+            ((a, b), (c, d)) if b - a > 3.0 * (d - c) => (c, d),
+            (range, _) => range,
+        };
+        let lo = points.iter().fold(lo, |a, b| a.min(*b));
+        let hi = points.iter().fold(hi, |a, b| a.max(*b));
+        // a step of 2 significant figures, so that x values are short
+        let step = (hi - lo) / (ROWS - 1) as f64;
+        let unit = 10f64.powi(step.log10().floor() as i32 - 1);
+        let step = (step / unit).ceil() * unit;
+        if !(step > 0.0 && step.is_finite()) {
+            return vec![];
+        }
+        let start = (lo / step).floor() * step;
+        let xs: Vec<_> = (0..ROWS)
+            .map(|i| (start + i as f64 * step).roundn(10))
+            .take_while(|x| *x <= hi)
+            .collect();
+        rows(&xs, points, |x| self.pdf(x))
+    }
+}
+
+#[test]
+fn test() {
+    let scale = Scale::new(0.0, 10.0, 11);
+    assert_eq!(scale.col(0.0), 0);
+    assert_eq!(scale.col(5.0), 5);
+    assert_eq!(scale.col(12.0), 10);
+    assert_eq!(Scale::new(1.0, 1.0, 11).col(1.0), 5);
+    let plot = boxplot(&scale, (1.0, 8.0), (3.0, 4.0, 6.0), &[10.0]);
+    assert_eq!(plot, " ├─[│═]─┤ ∘");
+    assert_eq!(bar(1.0, 3), "███");
+    assert_eq!(bar(0.5, 3), "█▌");
+    assert_eq!(bar(0.0, 3), "");
+    assert_eq!(region(&3, &[1, 3, 5]), 1);
+    assert_eq!(region(&4.0, &[1.0, 3.0, 5.0]), 2);
+    assert_eq!(shaded(0.1, 1), "░░░░░");
+
+    // rows step by 2 here, but both points still get a row
+    let dist = crate::distributions::Binomial::new(100, 0.5).unwrap();
+    let descs: Vec<_> =
+        dist.plot(&[30, 57]).into_iter().map(|v| v.desc).collect();
+    assert!(descs.contains(&"x = 30".to_string()));
+    assert!(descs.contains(&"x = 57".to_string()));
+
+    // the plot of C(0, 1) keeps to around its central 98%, between ±31.8
+    let dist = crate::distributions::Cauchy::new(0.0, 1.0).unwrap();
+    let plot = dist.plot(&[]);
+    assert_eq!(plot[0].desc, "x = -32.4");
+    assert_eq!(plot[plot.len() - 1].desc, "x = 29.7");
+}
@@ -1,59 +1,64 @@
 use crate::display::Line;
+use crate::math::Precision;
 use std::fmt::{self, Display, Formatter};
 
-struct PrintLine(String, String);
+/// a line of text, with its right side unless it is a header
+struct PrintLine(String, Option<String>);
 
 pub struct Printer {
     margin: usize,
     lines: Vec<PrintLine>,
     title: String,
+    precision: Precision,
 }
 
 /// print with a margin so that all the vertical pipes '|' line up nicely
 fn margin(left: impl Display, right: impl Display, margin: usize) -> String {
     let (l, r) = (left.to_string(), right.to_string());
-    let spaces = margin.checked_sub(l.len()).unwrap_or(0);
+    let spaces = margin.saturating_sub(l.chars().count());
     let spaces = (0..spaces).map(|_| " ").collect::<String>();
-    match r.is_empty() {
-        true => format!("{}", l),
-        false => format!("{}{} | {}", l, spaces, r),
+    match (l.is_empty(), r.is_empty()) {
+        (true, _) => r,
+        (false, true) => format!("{}{} |", l, spaces),
+        (false, false) => format!("{}{} | {}", l, spaces, r),
     }
 }
 
 impl Printer {
-    /// create a new printer
-    pub fn new() -> Self {
-        Self { margin: 0, lines: Vec::new(), title: String::new() }
+    /// create a new printer showing values with the given precision
+    pub fn new(precision: Precision) -> Self {
+        let (lines, title) = (Vec::new(), String::new());
+        Self { margin: 0, lines, title, precision }
     }
 
     /// updates the margin to accomodate this left text
     fn update_margin(&mut self, w: &str) {
-        self.margin = self.margin.max(w.len());
-    }
-
-    /// creates a line with a description and a value
-    /// and pushes it to the printer
-    pub fn raw_line(&mut self, desc: &str, val: Option<impl Display>) {
-        if let Some(val) = val {
-            self.update_margin(desc);
-            let [desc, val] = [desc.to_string(), val.to_string()];
-            self.lines.push(PrintLine(desc, val));
-        }
+        self.margin = self.margin.max(w.chars().count());
     }
 
     /// add a line to the printer
+    /// (headers have no value, and do not affect the margin)
     pub fn push_line(&mut self, line: &Line) {
+        let right = match (line.val, &line.text) {
+            (Some(v), _) => self.precision.format(v),
+            (None, Some(text)) => text.to_string(),
+            (None, None) => {
+                let header = PrintLine(format!("[{}]", line.desc), None);
+                return self.lines.push(header);
+            }
+        };
         self.update_margin(&line.desc);
-        let desc = line.desc.to_string();
-        let right = line.val.map(|v| v.to_string()).unwrap_or_default();
-        self.lines.push(PrintLine(desc, right));
+        self.lines.push(PrintLine(line.desc.to_string(), Some(right)));
     }
 
     /// compose one large string from all stored lines
     fn build(&self) -> String {
         self.lines
             .iter()
-            .map(|v| margin(&v.0, &v.1, self.margin))
+            .map(|v| match &v.1 {
+                Some(right) => margin(&v.0, right, self.margin),
+                None => v.0.to_string(),
+            })
             .collect::<Vec<String>>()
             .join("\n")
     }
@@ -62,12 +67,205 @@ impl Printer {
     pub fn set_title(&mut self, title: &str) {
         self.title = title.to_string();
     }
+}
 
+impl Display for Printer {
     /// write contents of print buffer to the formatter for printing
-    pub fn flush(&mut self, f: &mut Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if !self.title.is_empty() {
             writeln!(f, "{}", self.title).ok();
         }
         write!(f, "{}", self.build())
     }
 }
+
+/// quote and escape a string for use in JSON
+fn json_str(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                out.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// JSON has no representation for NaN or infinities
+fn json_num(v: f64) -> String {
+    match v.is_finite() {
+        true => v.to_string(),
+        false => "null".to_string(),
+    }
+}
+
+/// render a title and its lines as a JSON object, with full-precision
+/// values. Headers are rendered as {"header": ...}, and text lines
+/// as {"desc": ..., "text": ...}.
+pub fn json(title: &str, lines: &[Line]) -> String {
+    let lines = lines
+        .iter()
+        .map(|v| match (v.val, &v.text) {
+            (Some(val), _) => format!(
+                "{{\"desc\":{},\"value\":{}}}",
+                json_str(&v.desc),
+                json_num(val)
+            ),
+            (None, Some(text)) => format!(
+                "{{\"desc\":{},\"text\":{}}}",
+                json_str(&v.desc),
+                json_str(text)
+            ),
+            (None, None) => format!("{{\"header\":{}}}", json_str(&v.desc)),
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{{\"title\":{},\"lines\":[{}]}}", json_str(title), lines)
+}
+
+/// render an error message as a JSON object
+pub fn json_error(msg: &str) -> String {
+    format!("{{\"error\":{}}}", json_str(msg))
+}
+
+/// the first column is headed by the title, if any
+fn column_header(title: &str) -> &str {
+    match title.is_empty() {
+        true => "description",
+        false => title,
+    }
+}
+
+/// quote a CSV field if it contains special characters
+fn csv_field(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
+
+/// TSV fields cannot contain tabs or newlines
+fn tsv_field(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+/// render a title and its lines as delimiter-separated values,
+/// with a header row. Headers are rendered as rows without a value.
+fn delimited(
+    title: &str,
+    lines: &[Line],
+    p: Precision,
+    sep: &str,
+    field: fn(&str) -> String,
+) -> String {
+    let row = |l: &str, r: &str| format!("{}{}{}", field(l), sep, field(r));
+    let mut rows = vec![row(column_header(title), "value")];
+    lines.iter().for_each(|v| match (v.val, &v.text) {
+        (Some(val), _) => rows.push(row(&v.desc, &p.format(val))),
+        (None, Some(text)) => rows.push(row(&v.desc, text)),
+        (None, None) => rows.push(row(&format!("[{}]", v.desc), "")),
+    });
+    rows.join("\n")
+}
+
+pub fn csv(title: &str, lines: &[Line], p: Precision) -> String {
+    delimited(title, lines, p, ",", csv_field)
+}
+
+pub fn tsv(title: &str, lines: &[Line], p: Precision) -> String {
+    delimited(title, lines, p, "\t", tsv_field)
+}
+
+/// render a title and its lines as a GitHub-flavored Markdown table
+pub fn markdown(title: &str, lines: &[Line], p: Precision) -> String {
+    let cell = |s: &str| s.replace('|', "\\|");
+    let mut rows = vec![
+        format!("| {} | value |", cell(column_header(title))),
+        "| --- | ---: |".to_string(),
+    ];
+    lines.iter().for_each(|v| match (v.val, &v.text) {
+        (Some(val), _) => {
+            rows.push(format!("| {} | {} |", cell(&v.desc), p.format(val)))
+        }
+        (None, Some(text)) => {
+            rows.push(format!("| {} | `{}` |", cell(&v.desc), cell(text)))
+        }
+        (None, None) => rows.push(format!("| **{}** | |", cell(&v.desc))),
+    });
+    rows.join("\n")
+}
+
+/// escape characters that are special in LaTeX
+fn latex_escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => format!("\\{c}"),
+            '~' => "\\textasciitilde{}".to_string(),
+            '^' => "\\textasciicircum{}".to_string(),
+            '\\' => "\\textbackslash{}".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// render a title and its lines as a LaTeX tabular environment
+pub fn latex(title: &str, lines: &[Line], p: Precision) -> String {
+    let mut rows = vec![
+        "\\begin{tabular}{l r}".to_string(),
+        "\\hline".to_string(),
+        format!("{} & value \\\\", latex_escape(column_header(title))),
+        "\\hline".to_string(),
+    ];
+    lines.iter().for_each(|v| match (v.val, &v.text) {
+        (Some(val), _) => rows.push(format!(
+            "{} & {} \\\\",
+            latex_escape(&v.desc),
+            p.format(val)
+        )),
+        (None, Some(text)) => rows.push(format!(
+            "{} & \\texttt{{{}}} \\\\",
+            latex_escape(&v.desc),
+            latex_escape(text)
+        )),
+        (None, None) => rows.push(format!(
+            "\\multicolumn{{2}}{{l}}{{\\textbf{{{}}}}} \\\\",
+            latex_escape(&v.desc)
+        )),
+    });
+    rows.push("\\hline".to_string());
+    rows.push("\\end{tabular}".to_string());
+    rows.join("\n")
+}
+
+#[test]
+fn test() {
+    let lines = [Line::new("P(X = \"1\")", Some(0.1)), Line::new("sec", None)];
+    assert_eq!(
+        json("X ~ B(1, 0.1)", &lines),
+        r#"{"title":"X ~ B(1, 0.1)","lines":[{"desc":"P(X = \"1\")","value":0.1},{"header":"sec"}]}"#
+    );
+    assert_eq!(json_num(f64::NAN), "null");
+    assert_eq!(json_error("bad\n"), r#"{"error":"bad\n"}"#);
+
+    let lines = [Line::new("a, b", Some(0.5)), Line::new("x_1", None)];
+    let p = Precision::default();
+    assert_eq!(csv("", &lines, p), "description,value\n\"a, b\",0.5\n[x_1],");
+    assert_eq!(tsv("T", &lines, p), "T\tvalue\na, b\t0.5\n[x_1]\t");
+    assert_eq!(
+        markdown("", &[Line::new("|x|", Some(1.0))], p),
+        "| description | value |\n| --- | ---: |\n| \\|x\\| | 1 |"
+    );
+    let lines = [Line::text("plot", "├─┤")];
+    assert_eq!(
+        json("", &lines),
+        r#"{"title":"","lines":[{"desc":"plot","text":"├─┤"}]}"#
+    );
+    assert_eq!(latex_escape("x_1 & 50%"), "x\\_1 \\& 50\\%");
+}
@@ -0,0 +1,153 @@
+use crate::confidence::{label, symmetric};
+use crate::display::LineList;
+use crate::distributions::StudentsT;
+use crate::hypothesis::p_value;
+use crate::inverse::Invert;
+use crate::utils::{err, Result};
+use crate::Area;
+
+/// least-squares fit of y = a + bx
+pub struct Regression {
+    n: f64,
+    mean_x: f64,
+    mean_y: f64,
+    sxx: f64,
+    sxy: f64,
+    syy: f64,
+}
+
+impl Regression {
+    pub fn new(points: &[(f64, f64)]) -> Result<Regression> {
+        let n = points.len() as f64;
+        if n < 3.0 {
+            return err("at least 3 points are needed.");
+        }
+        let mean_x = points.iter().map(|v| v.0).sum::<f64>() / n;
+        let mean_y = points.iter().map(|v| v.1).sum::<f64>() / n;
+        let sum = |f: &dyn Fn(f64, f64) -> f64| {
+            points.iter().map(|v| f(v.0 - mean_x, v.1 - mean_y)).sum::<f64>()
+        };
+        let (sxx, sxy, syy) =
+            (sum(&|x, _| x * x), sum(&|x, y| x * y), sum(&|_, y| y * y));
+        if sxx == 0.0 {
+            return err("x values must not all be equal.");
+        }
+        Ok(Regression { n, mean_x, mean_y, sxx, sxy, syy })
+    }
+
+    pub fn slope(&self) -> f64 {
+        self.sxy / self.sxx
+    }
+
+    pub fn intercept(&self) -> f64 {
+        self.mean_y - self.slope() * self.mean_x
+    }
+
+    pub fn freedom(&self) -> f64 {
+        self.n - 2.0
+    }
+
+    /// sum of squared residuals
+    fn sse(&self) -> f64 {
+        (self.syy - self.slope() * self.sxy).max(0.0)
+    }
+
+    /// coefficient of determination
+    pub fn r_squared(&self) -> f64 {
+        match self.syy {
+            syy if syy > 0.0 => 1.0 - self.sse() / syy,
+            _ => 1.0,
+        }
+    }
+
+    /// residual standard error, estimating the standard deviation of y
+    pub fn residual_std_err(&self) -> f64 {
+        (self.sse() / self.freedom()).sqrt()
+    }
+
+    /// standard errors of the intercept and slope
+    pub fn std_errs(&self) -> (f64, f64) {
+        let s = self.residual_std_err();
+        let a = s * (1.0 / self.n + self.mean_x.powi(2) / self.sxx).sqrt();
+        (a, s / self.sxx.sqrt())
+    }
+
+    /// estimate, standard error, t statistic and p-value of a
+    /// coefficient, for H0: coefficient = 0. A perfect fit has no
+    /// residual error, so t and its p-value are left out.
+    fn coefficient(
+        dist: &StudentsT,
+        alpha: f64,
+        estimate: f64,
+        std_err: f64,
+    ) -> LineList {
+        let mut list = LineList::new();
+        let t = estimate / std_err;
+        list.push("estimate", estimate);
+        list.push("standard error", std_err);
+        if t.is_finite() {
+            list.push("t statistic", t);
+            list.append(&p_value(dist, t, Area::Mid));
+        }
+        list.append(&symmetric(dist, alpha, estimate, std_err));
+        list
+    }
+
+    /// fitted value at x, with a confidence interval for the mean
+    /// response and a prediction interval for a new observation
+    fn predict(&self, dist: &StudentsT, alpha: f64, x: f64) -> LineList {
+        let mut list = LineList::new();
+        let y = self.intercept() + self.slope() * x;
+        let s = self.residual_std_err();
+        let h = 1.0 / self.n + (x - self.mean_x).powi(2) / self.sxx;
+        list.push("fitted value", y);
+        list.append(&symmetric(dist, alpha, y, s * h.sqrt()));
+        let (a, b) = dist.bounds(1.0 - alpha);
+        let label = label(alpha).replace("CI", "PI");
+        let se = s * (1.0 + h).sqrt();
+        list.push(&format!("{label} lower bound"), y + a * se);
+        list.push(&format!("{label} upper bound"), y + b * se);
+        list
+    }
+
+    pub fn export(&self, alpha: f64, xs: &[f64]) -> Result<LineList> {
+        let dist = StudentsT::with_freedom(self.freedom())?;
+        let (se_a, se_b) = self.std_errs();
+        let mut list = LineList::new();
+        list.set_title("y = a + bx");
+        list.push("sample size", self.n);
+        list.push("degrees of freedom", self.freedom());
+        list.push("R²", self.r_squared());
+        list.push("residual std.err", self.residual_std_err());
+        list.header("intercept a");
+        list.append(&Self::coefficient(&dist, alpha, self.intercept(), se_a));
+        list.header("slope b");
+        list.append(&Self::coefficient(&dist, alpha, self.slope(), se_b));
+        for x in xs {
+            list.header(&format!("x = {x}"));
+            list.append(&self.predict(&dist, alpha, *x));
+        }
+        Ok(list)
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    let points = [(1.0, 2.0), (2.0, 4.1), (3.0, 5.9), (4.0, 8.2), (5.0, 9.8)];
+    let fit = Regression::new(&points)?;
+    float_eq!(fit.slope(), 1.97);
+    float_eq!(fit.intercept(), 0.09);
+    float_eq!(fit.r_squared(), 0.9976606684);
+    float_eq!(fit.residual_std_err(), 0.174164673);
+    let (se_a, se_b) = fit.std_errs();
+    float_eq!(se_a, 0.1826654501);
+    float_eq!(se_b, 0.0550757055);
+    assert!(Regression::new(&points[..2]).is_err());
+    assert!(Regression::new(&[(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)]).is_err());
+
+    // a perfect fit has no t statistics
+    let line = [(1.0, 2.0), (2.0, 4.0), (3.0, 6.0), (4.0, 8.0)];
+    let list = Regression::new(&line)?.export(0.05, &[5.0])?;
+    assert!(list.list.iter().all(|v| v.desc != "t statistic"));
+    Ok(())
+}
@@ -7,14 +7,10 @@ pub fn err<T>(msg: &str) -> Result<T> {
 }
 
 pub trait ResultOps<T, E> {
-    fn clear(self) -> core::Result<(), E>;
     fn serr(self, msg: &str) -> core::Result<T, String>;
 }
 
 impl<T, E> ResultOps<T, E> for core::Result<T, E> {
-    fn clear(self) -> core::Result<(), E> {
-        self.map(|_| ())
-    }
     fn serr(self, msg: &str) -> core::Result<T, String> {
         self.map_err(|_| msg.to_string())
     }
@@ -22,13 +18,13 @@ impl<T, E> ResultOps<T, E> for core::Result<T, E> {
 
 /// Evaluate a math expression to a floating-point value
 pub fn eval_f64(s: &str) -> Result<f64> {
-    return meval::eval_str(s).serr("Invalid expression.");
+    meval::eval_str(s).serr("Invalid expression.")
 }
 
 /// Evaluate a math expression to a floating-point probability
 pub fn eval_prob(s: &str) -> Result<f64> {
     let p = meval::eval_str(s).serr("Invalid expression.")?;
-    if p < 0.0 || p > 1.0 {
+    if !(0.0..=1.0).contains(&p) {
         return err("Probability values must be between 0 and 1.");
     }
     Ok(p)
@@ -42,3 +38,18 @@ pub fn eval_u64(s: &str) -> Result<u64> {
         false => Ok(v as u64),
     }
 }
+
+/// Comma-separated lists are parsed as a single argument. (clap would
+/// instead take a Vec field as one argument per value.)
+pub type Probabilities = Vec<f64>;
+pub type Counts = Vec<u64>;
+
+/// Evaluate a comma-separated list of probabilities
+pub fn eval_probs(s: &str) -> Result<Probabilities> {
+    s.split(',').map(|v| eval_prob(v.trim())).collect()
+}
+
+/// Evaluate a comma-separated list of unsigned integers
+pub fn eval_counts(s: &str) -> Result<Counts> {
+    s.split(',').map(|v| eval_u64(v.trim())).collect()
+}
@@ -35,12 +35,115 @@ P(X > 10)      | 0
 ",
     );
 
+    statc_test!().statc("binom 10 0.2 --moments").expect_stdout(
+        "
+---
+X ~ B(10, 0.2)
+expected        | 2
+variance        | 1.6
+median          | 2
+mode            | 2
+skewness        | 0.474341649
+excess kurtosis | 0.025
+entropy (nats)  | 1.6219291434
+E(X²)           | 5.6
+E(X³)           | 18.56
+E(X⁴)           | 69.824
+E((X - μ)³)     | 0.96
+E((X - μ)⁴)     | 7.744
+",
+    );
+
+    // a constant has central moments of 0 but no skewness or kurtosis
+    statc_test!().statc("binom 5 1 --moments").expect_stdout(
+        "
+---
+X ~ B(5, 1)
+expected       | 5
+variance       | 0
+median         | 5
+mode           | 5
+entropy (nats) | 0
+E(X²)          | 25
+E(X³)          | 125
+E(X⁴)          | 625
+E((X - μ)³)    | 0
+E((X - μ)⁴)    | 0
+",
+    );
+
+    // Var[X] is summed around the mean, where E[X²] - E[X]² cancels
+    statc_test!().statc("binom 1000000000 0.5 -e x").expect_stdout(
+        "
+---
+X ~ B(1000000000, 0.5)
+expected | 500000000
+variance | 250000000
+E[x]     | 500000000
+Var[x]   | 250000000
+",
+    );
+
     statc_test!().statc("binom 10 0.2 0.4").expect_stderr(
         "
 ---
 error: Invalid value '0.4' for '[WINS]...': Not an integer.
 
 For more information try '--help'
+",
+    );
+
+    statc_test!().statc("binom 10 0.2 1 3 --plot").expect_stdout(
+        "
+---
+X ~ B(10, 0.2)
+expected      | 2
+variance      | 1.6
+P(X = 1)      | 0.268435456
+P(X = 3)      | 0.201326592
+P(X <= 1)     | 0.3758096384
+P(1 < X <= 3) | 0.50331648
+P(X > 3)      | 0.1208738816
+x = 0         | █████████████████▊
+x = 1         | ████████████████████████████████████████████▌
+x = 2         | ░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░
+x = 3         | ░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░
+x = 4         | ██████████████▋
+x = 5         | ████▍
+x = 6         | ▉
+",
+    );
+
+    statc_test!().statc("binom 20 0.4 5 8 --approx normal").expect_stdout(
+        "
+---
+X ~ B(20, 0.4)
+expected                 | 8
+variance                 | 4.8
+P(X = 5)                 | 0.0746470195
+P(X = 8)                 | 0.1797057878
+P(X <= 5)                | 0.1255989727
+P(5 < X <= 8)            | 0.4699997526
+P(X > 8)                 | 0.4044012747
+[normal approximation N(8, 4.8)]
+[P(X <= 5)]
+exact                    | 0.1255989727
+normal                   | 0.0854517601
+absolute error           | 0.0401472126
+continuity corrected     | 0.1269165146
+corrected absolute error | 0.0013175419
+[P(5 < X <= 8)]
+exact                    | 0.4699997526
+normal                   | 0.4145482399
+absolute error           | 0.0554515127
+continuity corrected     | 0.463344997
+corrected absolute error | 0.0066547556
+[P(X > 8)]
+exact                    | 0.4044012747
+normal                   | 0.5
+absolute error           | 0.0955987253
+continuity corrected     | 0.4097384884
+corrected absolute error | 0.0053372137
 ",
     );
 }
@@ -56,6 +159,25 @@ variance | 31.1111111111
 ",
     );
 
+    statc_test!().statc("nbinom 5 1e-8 --moments").expect_stdout(
+        "
+---
+X ~ NB(5, 0.00000001)
+expected        | 500000000
+variance        | 4.99999995e16
+median          | 467090888
+mode            | 400000000
+skewness        | 0.894427191
+excess kurtosis | 1.2
+entropy (nats)  | 20.5742638956
+E(X²)           | 2.999999995e17
+E(X³)           | 2.099999991e26
+E(X⁴)           | 1.6799999874e35
+E((X - μ)³)     | 9.99999985e24
+E((X - μ)⁴)     | 1.049999979e34
+",
+    );
+
     statc_test!().statc("nbinom 4 0.3 3").expect_stdout(
         "
 ---
@@ -157,6 +279,245 @@ P(3 < X <= 5) | 0.3577395191
 P(X > 5)      | 0.2468571113
 ",
     );
+
+    statc_test!().statc("pois 3 --expect x^2").expect_stdout(
+        "
+---
+X ~ Poisson(3)
+expected | 3
+variance | 3
+E[x^2]   | 12
+Var[x^2] | 165
+",
+    );
+}
+
+#[test]
+fn hypergeom_test() {
+    statc_test!().statc("hypergeom 20 7 5 1 2").expect_stdout(
+        "
+---
+X ~ H(20, 7, 5)
+expected      | 1.75
+variance      | 0.8980263158
+P(X = 1)      | 0.3228199174
+P(X = 2)      | 0.3873839009
+P(X <= 1)     | 0.4058307534
+P(1 < X <= 2) | 0.3873839009
+P(X > 2)      | 0.2067853457
+",
+    );
+
+    // closed forms and tail sums rather than sums over the whole support
+    statc_test!()
+        .statc("hypergeom 1000000000 500000000 100000000 --moments")
+        .expect_stdout(
+            "
+---
+X ~ H(1000000000, 500000000, 100000000)
+expected        | 50000000
+variance        | 22500000.0225
+median          | 50000000
+mode            | 50000000
+skewness        | 0
+excess kurtosis | -0.0000000162
+entropy (nats)  | 9.8834514673
+E(X²)           | 2500000022500000
+E(X³)           | 1.2500000337e23
+E(X⁴)           | 6.2500003375e30
+E((X - μ)³)     | 0
+E((X - μ)⁴)     | 1518749994825000
+",
+        );
+
+    statc_test!().statc("hypergeom 20 21 5").expect_stdout(
+        "
+---
+Successes cannot exceed the population size.
+",
+    );
+}
+
+#[test]
+fn dunif_test() {
+    statc_test!().statc("dunif 1 6 2 4").expect_stdout(
+        "
+---
+X ~ DU(1, 6)
+expected      | 3.5
+variance      | 2.9166666667
+P(X = 2)      | 0.1666666667
+P(X = 4)      | 0.1666666667
+P(X <= 2)     | 0.3333333333
+P(2 < X <= 4) | 0.3333333333
+P(X > 4)      | 0.3333333333
+",
+    );
+}
+
+#[test]
+fn bernoulli_test() {
+    statc_test!().statc("bernoulli 0.3 0").expect_stdout(
+        "
+---
+X ~ Ber(0.3)
+expected  | 0.3
+variance  | 0.21
+P(X = 0)  | 0.7
+P(X <= 0) | 0.7
+P(X > 0)  | 0.3
+",
+    );
+
+    // cdf(0) falls just short of 0.5, but the median is still 0, and
+    // the symmetric distribution has no skew
+    statc_test!().statc("bernoulli 0.5 --moments").expect_stdout(
+        "
+---
+X ~ Ber(0.5)
+expected        | 0.5
+variance        | 0.25
+median          | 0
+mode            | 0
+skewness        | 0
+excess kurtosis | -2
+entropy (nats)  | 0.6931471806
+E(X²)           | 0.5
+E(X³)           | 0.5
+E(X⁴)           | 0.5
+E((X - μ)³)     | 0
+E((X - μ)⁴)     | 0.0625
+",
+    );
+}
+
+#[test]
+fn categorical_test() {
+    statc_test!().statc("categorical 0.2,0.3,0.5 0 1").expect_stdout(
+        "
+---
+X ~ Cat(0.2, 0.3, 0.5)
+expected      | 1.3
+variance      | 0.61
+P(X = 0)      | 0.2
+P(X = 1)      | 0.3
+P(X <= 0)     | 0.2
+P(0 < X <= 1) | 0.3
+P(X > 1)      | 0.5
+",
+    );
+
+    statc_test!().statc("categorical 0.2,0.3 1").expect_stdout(
+        "
+---
+Probabilities must sum to 1.
+",
+    );
+}
+
+#[test]
+fn multinom_test() {
+    statc_test!().statc("multinom 0.2,0.3,0.5 1,2,3").expect_stdout(
+        "
+---
+X ~ M(6; 0.2, 0.3, 0.5)
+[X1]
+expected                  | 1.2
+variance                  | 0.96
+[X2]
+expected                  | 1.8
+variance                  | 1.26
+[X3]
+expected                  | 3
+variance                  | 1.5
+[probability]
+P(X1 = 1, X2 = 2, X3 = 3) | 0.135
+",
+    );
+
+    statc_test!().statc("multinom 1/6,1/3,1/2 -n 3").expect_stdout(
+        "
+---
+X ~ M(3; 0.1666666667, 0.3333333333, 0.5)
+[X1]
+expected | 0.5
+variance | 0.4166666667
+[X2]
+expected | 1
+variance | 0.6666666667
+[X3]
+expected | 1.5
+variance | 0.75
+",
+    );
+}
+
+#[test]
+fn pmf_test() {
+    statc_test!()
+        .file_with_text(
+            "dist",
+            "
+0 0.2
+1 0.5
+3 0.3",
+        )
+        .statc("pmf dist 1 --plot -e x^2")
+        .expect_stdout(
+            "
+---
+X ~ dist
+expected  | 1.4
+variance  | 1.24
+P(X = 1)  | 0.5
+P(X <= 1) | 0.7
+P(X > 1)  | 0.3
+E[x^2]    | 3.2
+Var[x^2]  | 14.56
+x = 0     | ████████████████████
+x = 1     | ██████████████████████████████████████████████████
+x = 2     |
+x = 3     | ░░░░░░░░░░░░░░░░░░░░░░░░░░░░░░
+",
+        );
+
+    // values alone are equally likely
+    statc_test!()
+        .file_with_text(
+            "dist",
+            "
+1
+2
+2
+5",
+        )
+        .statc("pmf dist 2")
+        .expect_stdout(
+            "
+---
+X ~ dist
+expected  | 2.5
+variance  | 2.25
+P(X = 2)  | 0.5
+P(X <= 2) | 0.75
+P(X > 2)  | 0.25
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "dist",
+            "
+0.5 0.5
+1 0.5",
+        )
+        .statc("pmf dist")
+        .expect_stdout(
+            "
+---
+Values must be non-negative integers.
+",
+        );
 }
 
 #[test]
@@ -231,6 +592,64 @@ pdf @ 5       | 0.0005367402
 P(X <= 3)     | 0.991770253
 P(3 < X <= 5) | 0.0078942844
 P(X > 5)      | 0.0003354626
+",
+    );
+
+    statc_test!().statc("exp 2 1 --plot").expect_stdout(
+        "
+---
+X ~ Exp(2)
+expected  | 0.5
+variance  | 0.25
+pdf @ 1   | 0.2706705665
+P(X <= 1) | 0.8646647168
+P(X > 1)  | 0.1353352832
+x = 0     | ██████████████████████████████████████████████████
+x = 0.15  | █████████████████████████████████████
+x = 0.3   | ███████████████████████████▌
+x = 0.45  | ████████████████████▍
+x = 0.6   | ███████████████
+x = 0.75  | ███████████▏
+x = 0.9   | ████████▎
+x = 1.05  | ░░░░░░
+x = 1.2   | ░░░░░
+x = 1.35  | ░░░
+x = 1.5   | ░░
+x = 1.65  | ░░
+x = 1.8   | ░
+x = 1.95  | ░
+x = 2.1   | ░
+x = 2.25  | ░
+x = 2.4   |
+x = 2.55  |
+x = 2.7   |
+x = 2.85  |
+x = 3     |
+x = 3.15  |
+x = 3.3   |
+x = 3.45  |
+",
+    );
+
+    // E[ln X] = -γ - ln 2 and Var[ln X] = π²/6
+    statc_test!().statc("exp 2 1 -e ln(x)").expect_stdout(
+        "
+---
+X ~ Exp(2)
+expected   | 0.5
+variance   | 0.25
+pdf @ 1    | 0.2706705665
+P(X <= 1)  | 0.8646647168
+P(X > 1)   | 0.1353352832
+E[ln(x)]   | -1.2703628455
+Var[ln(x)] | 1.6449340669
+",
+    );
+
+    statc_test!().statc("exp 2 -e y").expect_stdout(
+        "
+---
+The expression may only use the variable x.
 ",
     );
 }
@@ -258,6 +677,19 @@ P(X > 3)  | 0.9997673709
 ",
     );
 
+    // far out in the tail, P(X > x) does not cancel to 0
+    statc_test!().statc("norm 0 1 10").expect_stdout(
+        "
+---
+X ~ N(0, 1²)
+expected   | 0
+variance   | 1
+pdf @ 10   | 7.6945986267e-23
+P(X <= 10) | 1
+P(X > 10)  | 7.6198530246e-24
+",
+    );
+
     statc_test!().statc("norm 10 2 0-1 3").expect_stdout(
         "
 ---
@@ -269,6 +701,63 @@ pdf @ 3        | 0.0004363413
 P(X <= -1)     | 0.000000019
 P(-1 < X <= 3) | 0.0002326101
 P(X > 3)       | 0.9997673709
+",
+    );
+
+    statc_test!().statc("norm 10 2 --moments").expect_stdout(
+        "
+---
+X ~ N(10, 2²)
+expected        | 10
+variance        | 4
+median          | 10
+mode            | 10
+skewness        | 0
+excess kurtosis | 0
+entropy (nats)  | 2.1120857138
+E(X²)           | 104
+E(X³)           | 1120
+E(X⁴)           | 12448
+E((X - μ)³)     | 0
+E((X - μ)⁴)     | 48
+",
+    );
+
+    // a large mean must not swamp the central moments
+    statc_test!().statc("norm 1000000 1 --moments").expect_stdout(
+        "
+---
+X ~ N(1000000, 1²)
+expected        | 1000000
+variance        | 1
+median          | 1000000
+mode            | 1000000
+skewness        | 0
+excess kurtosis | 0
+entropy (nats)  | 1.4189385332
+E(X²)           | 1000000000001
+E(X³)           | 1e18
+E(X⁴)           | 1e24
+E((X - μ)³)     | 0
+E((X - μ)⁴)     | 3
+",
+    );
+
+    statc_test!().statc("norm 0 1 -e x^2").expect_stdout(
+        "
+---
+X ~ N(0, 1²)
+expected | 0
+variance | 1
+E[x^2]   | 1
+Var[x^2] | 2
+",
+    );
+
+    statc_test!().statc("norm 0 1 -e sqrt(x)").expect_stdout(
+        "
+---
+sqrt(x) is not defined everywhere X may fall.
 ",
     );
 }
@@ -388,171 +877,2033 @@ P(X > 1.2)      | 0.4136092469
 }
 
 #[test]
-fn inorm_test() {
-    statc_test!().statc("inorm 0 1 mid 0.95").expect_stdout(
+fn gamma_test() {
+    statc_test!().statc("gamma 2 0.5").expect_stdout(
         "
 ---
-X ~ N(0, 1²)
-a: left bound  | -1.9599639845
-b: right bound | 1.9599639845
-P(a < X < b)   | 0.95
+X ~ Gamma(2, 0.5)
+expected | 4
+variance | 8
 ",
     );
 
-    statc_test!().statc("inorm 0 1 left 0.05").expect_stdout(
+    statc_test!().statc("gamma 2 0.5 3 6").expect_stdout(
         "
 ---
-X ~ N(0, 1²)
-x: right bound | -1.644853627
-P(X > x)       | 0.05
+X ~ Gamma(2, 0.5)
+expected      | 4
+variance      | 8
+pdf @ 3       | 0.1673476201
+pdf @ 6       | 0.0746806026
+P(X <= 3)     | 0.4421745996
+P(3 < X <= 6) | 0.3586771269
+P(X > 6)      | 0.1991482735
 ",
     );
+}
 
-    statc_test!().statc("inorm 0 1 right 0.05").expect_stdout(
+#[test]
+fn beta_test() {
+    statc_test!().statc("beta 2 5 0.2 0.5").expect_stdout(
         "
 ---
-X ~ N(0, 1²)
-x: left bound | 1.644853627
-P(X > x)      | 0.05
+X ~ Beta(2, 5)
+expected          | 0.2857142857
+variance          | 0.0255102041
+pdf @ 0.2         | 2.4576
+pdf @ 0.5         | 0.9375
+P(X <= 0.2)       | 0.34464
+P(0.2 < X <= 0.5) | 0.545985
+P(X > 0.5)        | 0.109375
 ",
     );
 }
 
 #[test]
-fn it_test() {
-    statc_test!().statc("it 5 mid 0.95").expect_stdout(
+fn lognorm_test() {
+    statc_test!().statc("lognorm 0 1 1 2").expect_stdout(
         "
 ---
-X ~ t(5)
-a: left bound  | -2.5705818356
-b: right bound | 2.5705818356
-P(a < X < b)   | 0.95
+X ~ LogN(0, 1²)
+expected      | 1.6487212707
+variance      | 4.6707742705
+pdf @ 1       | 0.3989422804
+pdf @ 2       | 0.1568740193
+P(X <= 1)     | 0.5
+P(1 < X <= 2) | 0.2558914042
+P(X > 2)      | 0.2441085958
 ",
     );
+}
 
-    statc_test!().statc("it 5 left 0.95").expect_stdout(
+#[test]
+fn weibull_test() {
+    statc_test!().statc("weibull 1.5 2 1").expect_stdout(
         "
 ---
-X ~ t(5)
-x: right bound | 2.0150483733
-P(X > x)       | 0.95
+X ~ W(1.5, 2)
+expected  | 1.8054905859
+variance  | 1.5027611393
+pdf @ 1   | 0.3723916882
+P(X <= 1) | 0.2978114987
+P(X > 1)  | 0.7021885013
 ",
     );
+}
 
-    statc_test!().statc("it 5 right 0.9").expect_stdout(
+#[test]
+fn cauchy_test() {
+    statc_test!().statc("cauchy 0 1 1").expect_stdout(
         "
 ---
-X ~ t(5)
-x: left bound | -1.4758840488
-P(X > x)      | 0.9
+X ~ C(0, 1)
+pdf @ 1   | 0.1591549431
+P(X <= 1) | 0.75
+P(X > 1)  | 0.25
 ",
     );
-}
 
-#[test]
-fn ichisq_test() {
-    statc_test!().statc("ichisq 5 0.9").expect_stdout(
+    statc_test!().statc("cauchy 0 1 --moments").expect_stdout(
         "
 ---
-X ~ χ²(5)
-x: left bound | 1.6103210449
-P(X > x)      | 0.9
+X ~ C(0, 1)
+median         | 0
+mode           | 0
+entropy (nats) | 2.531024247
+",
+    );
+
+    // the mean of the Cauchy distribution does not exist
+    statc_test!().statc("cauchy 0 1 -e x").expect_stdout(
+        "
+---
+E[x] does not exist.
+",
+    );
+
+    // what the integral misses of the tails leaves no spread in a constant
+    statc_test!().statc("cauchy 0 1 -e 1").expect_stdout(
+        "
+---
+X ~ C(0, 1)
+E[1]   | 1
+Var[1] | 0
 ",
     );
 }
 
 #[test]
-fn vpool_test() {
-    statc_test!().statc("vpool 3 2.3 4 5.4").expect_stdout(
+fn laplace_test() {
+    statc_test!().statc("laplace 0 1 1").expect_stdout(
         "
 ---
-[1] sample size        | 3
-[1] sample variance    | 2.3
-[2] sample size        | 4
-[2] sample variance    | 5.4
-pooled sample variance | 4.16
-pooled sample std.dev  | 2.0396078054
+X ~ L(0, 1)
+expected  | 0
+variance  | 2
+pdf @ 1   | 0.1839397206
+P(X <= 1) | 0.8160602794
+P(X > 1)  | 0.1839397206
 ",
     );
 }
 
 #[test]
-fn eval_test() {
-    statc_test!().statc("eval 1+2+3+4").expect_stdout("10\n");
-    statc_test!().statc("eval 5/2").expect_stdout("2.5\n");
+fn pareto_test() {
+    statc_test!().statc("pareto 1 3 2").expect_stdout(
+        "
+---
+X ~ Pa(1, 3)
+expected  | 1.5
+variance  | 0.75
+pdf @ 2   | 0.1875
+P(X <= 2) | 0.875
+P(X > 2)  | 0.125
+",
+    );
 }
 
 #[test]
-fn data_test() {
-    statc_test!()
-        .file_with_text(
-            "cool_filename",
-            "
-10
-20
-30
-50",
-        )
-        .statc("data cool_filename")
+fn ibinom_test() {
+    statc_test!().statc("ibinom 10 0.3 left 0.95").expect_stdout(
+        "
+---
+X ~ B(10, 0.3)
+x: right bound | 5
+P(X <= x)      | 0.9526510126
+P(X < x)       | 0.8497316674
+",
+    );
+
+    statc_test!().statc("ibinom 10 0.3 right 0.05").expect_stdout(
+        "
+---
+X ~ B(10, 0.3)
+x: left bound | 5
+P(X > x)      | 0.0473489874
+P(X >= x)     | 0.1502683326
+",
+    );
+
+    statc_test!().statc("ibinom 10 0.3 mid 0.9").expect_stdout(
+        "
+---
+X ~ B(10, 0.3)
+a: left bound  | 1
+b: right bound | 5
+P(a <= X <= b) | 0.9244034877
+P(X < a)       | 0.0282475249
+P(X > b)       | 0.0473489874
+",
+    );
+}
+
+#[test]
+fn inbinom_test() {
+    statc_test!().statc("inbinom 4 0.3 mid 0.95").expect_stdout(
+        "
+---
+X ~ NB(4, 0.3)
+a: left bound  | 5
+b: right bound | 27
+P(a <= X <= b) | 0.9717073382
+P(X < a)       | 0.0081
+P(X > b)       | 0.0201926618
+",
+    );
+}
+
+#[test]
+fn igeom_test() {
+    statc_test!().statc("igeom 0.2 right 0.1").expect_stdout(
+        "
+---
+X ~ G(0.2)
+x: left bound | 11
+P(X > x)      | 0.0858993459
+P(X >= x)     | 0.1073741824
+",
+    );
+}
+
+#[test]
+fn ipois_test() {
+    statc_test!().statc("ipois 4.2 left 0.5").expect_stdout(
+        "
+---
+X ~ Poisson(4.2)
+x: right bound | 4
+P(X <= x)      | 0.5898270213
+P(X < x)       | 0.3954033696
+",
+    );
+}
+
+#[test]
+fn inorm_test() {
+    statc_test!().statc("inorm 0 1 mid 0.95").expect_stdout(
+        "
+---
+X ~ N(0, 1²)
+a: left bound  | -1.9599639845
+b: right bound | 1.9599639845
+P(a < X < b)   | 0.95
+",
+    );
+
+    statc_test!().statc("inorm 0 1 left 0.05").expect_stdout(
+        "
+---
+X ~ N(0, 1²)
+x: right bound | -1.644853627
+P(X > x)       | 0.05
+",
+    );
+
+    statc_test!().statc("inorm 0 1 right 0.05").expect_stdout(
+        "
+---
+X ~ N(0, 1²)
+x: left bound | 1.644853627
+P(X > x)      | 0.05
+",
+    );
+}
+
+#[test]
+fn it_test() {
+    statc_test!().statc("it 5 mid 0.95").expect_stdout(
+        "
+---
+X ~ t(5)
+a: left bound  | -2.5705818356
+b: right bound | 2.5705818356
+P(a < X < b)   | 0.95
+",
+    );
+
+    statc_test!().statc("it 5 left 0.95").expect_stdout(
+        "
+---
+X ~ t(5)
+x: right bound | 2.0150483733
+P(X > x)       | 0.95
+",
+    );
+
+    statc_test!().statc("it 5 right 0.9").expect_stdout(
+        "
+---
+X ~ t(5)
+x: left bound | -1.4758840488
+P(X > x)      | 0.9
+",
+    );
+}
+
+#[test]
+fn ichisq_test() {
+    statc_test!().statc("ichisq 5 right 0.9").expect_stdout(
+        "
+---
+X ~ χ²(5)
+x: left bound | 1.610307987
+P(X > x)      | 0.9
+",
+    );
+
+    // AREA defaults to right, as it did before it was added
+    statc_test!().statc("ichisq 5 0.9").expect_stdout(
+        "
+---
+X ~ χ²(5)
+x: left bound | 1.610307987
+P(X > x)      | 0.9
+",
+    );
+
+    statc_test!().statc("ichisq 5 mid 0.9").expect_stdout(
+        "
+---
+X ~ χ²(5)
+a: left bound  | 1.1454762261
+b: right bound | 11.0704976935
+P(a < X < b)   | 0.9
+",
+    );
+}
+
+#[test]
+fn if_test() {
+    statc_test!().statc("if 4 5 right 0.05").expect_stdout(
+        "
+---
+X ~ F(4, 5)
+x: left bound | 5.1921539307
+P(X > x)      | 0.05
+",
+    );
+}
+
+#[test]
+fn iexp_test() {
+    statc_test!().statc("iexp 0.5 left 0.5").expect_stdout(
+        "
+---
+X ~ Exp(0.5)
+x: right bound | 1.3862943611
+P(X > x)       | 0.5
+",
+    );
+}
+
+#[test]
+fn iunif_test() {
+    statc_test!().statc("iunif 2 4 mid 0.5").expect_stdout(
+        "
+---
+X ~ U(2, 4)
+a: left bound  | 2.5
+b: right bound | 3.5
+P(a < X < b)   | 0.5
+",
+    );
+}
+
+#[test]
+fn igamma_test() {
+    statc_test!().statc("igamma 2 0.5 left 0.9").expect_stdout(
+        "
+---
+X ~ Gamma(2, 0.5)
+x: right bound | 7.7794403397
+P(X > x)       | 0.9
+",
+    );
+}
+
+#[test]
+fn ibeta_test() {
+    statc_test!().statc("ibeta 2 5 mid 0.95").expect_stdout(
+        "
+---
+X ~ Beta(2, 5)
+a: left bound  | 0.0432718683
+b: right bound | 0.641234579
+P(a < X < b)   | 0.95
+",
+    );
+}
+
+#[test]
+fn ilognorm_test() {
+    statc_test!().statc("ilognorm 0 1 left 0.9").expect_stdout(
+        "
+---
+X ~ LogN(0, 1²)
+x: right bound | 3.6022244793
+P(X > x)       | 0.9
+",
+    );
+}
+
+#[test]
+fn iweibull_test() {
+    statc_test!().statc("iweibull 1.5 2 left 0.5").expect_stdout(
+        "
+---
+X ~ W(1.5, 2)
+x: right bound | 1.5664395375
+P(X > x)       | 0.5
+",
+    );
+}
+
+#[test]
+fn icauchy_test() {
+    statc_test!().statc("icauchy 0 1 left 0.975").expect_stdout(
+        "
+---
+X ~ C(0, 1)
+x: right bound | 12.7062047362
+P(X > x)       | 0.975
+",
+    );
+}
+
+#[test]
+fn ilaplace_test() {
+    statc_test!().statc("ilaplace 0 1 left 0.9").expect_stdout(
+        "
+---
+X ~ L(0, 1)
+x: right bound | 1.6094379124
+P(X > x)       | 0.9
+",
+    );
+}
+
+#[test]
+fn ipareto_test() {
+    statc_test!().statc("ipareto 1 3 left 0.5").expect_stdout(
+        "
+---
+X ~ Pa(1, 3)
+x: right bound | 1.2599210499
+P(X > x)       | 0.5
+",
+    );
+}
+
+#[test]
+fn vpool_test() {
+    statc_test!().statc("vpool 3 2.3 4 5.4").expect_stdout(
+        "
+---
+[1] sample size        | 3
+[1] sample variance    | 2.3
+[2] sample size        | 4
+[2] sample variance    | 5.4
+pooled sample variance | 4.16
+pooled sample std.dev  | 2.0396078054
+",
+    );
+}
+
+#[test]
+fn anova_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+6
+8
+4
+5
+3
+4",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+8
+12
+9
+11
+6
+8",
+        )
+        .file_with_text(
+            "data_set_3",
+            "
+13
+9
+11
+8
+7
+12",
+        )
+        .statc("anova data_set_1 data_set_2 data_set_3")
+        .expect_stdout(
+            "
+---
+H0: all population means are equal
+[data_set_1]
+sample size          | 6
+mean                 | 5
+sample variance      | 3.2
+[data_set_2]
+sample size          | 6
+mean                 | 9
+sample variance      | 4.8
+[data_set_3]
+sample size          | 6
+mean                 | 10
+sample variance      | 5.6
+[between groups]
+sum of squares       | 84
+degrees of freedom   | 2
+mean square          | 42
+[within groups]
+sum of squares       | 68
+degrees of freedom   | 15
+mean square          | 4.5333333333
+[total]
+sum of squares       | 152
+degrees of freedom   | 17
+[F-test]
+F statistic          | 9.2647058824
+right-tailed p-value | 0.0023987773
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+a 6
+a 8
+a 4
+b 8
+b 12
+b 9
+c 13
+c 9
+c 11
+a 5
+b 11
+c 8",
+        )
+        .statc("anova data_set_1 -g -p bonferroni")
+        .expect_stdout(
+            "
+---
+H0: all population means are equal
+[a]
+sample size          | 4
+mean                 | 5.75
+sample variance      | 2.9166666667
+[b]
+sample size          | 4
+mean                 | 10
+sample variance      | 3.3333333333
+[c]
+sample size          | 4
+mean                 | 10.25
+sample variance      | 4.9166666667
+[between groups]
+sum of squares       | 51.1666666667
+degrees of freedom   | 2
+mean square          | 25.5833333333
+[within groups]
+sum of squares       | 33.5
+degrees of freedom   | 9
+mean square          | 3.7222222222
+[total]
+sum of squares       | 84.6666666667
+degrees of freedom   | 11
+[F-test]
+F statistic          | 6.8731343284
+right-tailed p-value | 0.0154168467
+[Bonferroni]
+[a - b]
+difference of means  | -4.25
+standard error       | 1.364225462
+t statistic          | -3.1153208311
+adjusted p-value     | 0.0372357125
+95% CI lower bound   | -8.2517154096
+95% CI upper bound   | -0.2482845904
+[a - c]
+difference of means  | -4.5
+standard error       | 1.364225462
+t statistic          | -3.2985749976
+adjusted p-value     | 0.0277527321
+95% CI lower bound   | -8.5017154096
+95% CI upper bound   | -0.4982845904
+[b - c]
+difference of means  | -0.25
+standard error       | 1.364225462
+t statistic          | -0.1832541665
+adjusted p-value     | 1
+95% CI lower bound   | -4.2517154096
+95% CI upper bound   | 3.7517154096
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+a 6
+a 8
+a 4
+b 8
+b 12
+b 9
+c 13
+c 9
+c 11
+a 5
+b 11
+c 8",
+        )
+        .statc("anova data_set_1 -g -p tukey --precision 4")
+        .expect_stdout(
+            "
+---
+H0: all population means are equal
+[a]
+sample size          | 4
+mean                 | 5.75
+sample variance      | 2.9167
+[b]
+sample size          | 4
+mean                 | 10
+sample variance      | 3.3333
+[c]
+sample size          | 4
+mean                 | 10.25
+sample variance      | 4.9167
+[between groups]
+sum of squares       | 51.1667
+degrees of freedom   | 2
+mean square          | 25.5833
+[within groups]
+sum of squares       | 33.5
+degrees of freedom   | 9
+mean square          | 3.7222
+[total]
+sum of squares       | 84.6667
+degrees of freedom   | 11
+[F-test]
+F statistic          | 6.8731
+right-tailed p-value | 0.0154
+[Tukey HSD]
+[a - b]
+difference of means  | -4.25
+standard error       | 1.3642
+q statistic          | 4.4057
+adjusted p-value     | 0.0302
+95% CI lower bound   | -8.0589
+95% CI upper bound   | -0.4411
+[a - c]
+difference of means  | -4.5
+standard error       | 1.3642
+q statistic          | 4.6649
+adjusted p-value     | 0.0227
+95% CI lower bound   | -8.3089
+95% CI upper bound   | -0.6911
+[b - c]
+difference of means  | -0.25
+standard error       | 1.3642
+q statistic          | 0.2592
+adjusted p-value     | 0.9817
+95% CI lower bound   | -4.0589
+95% CI upper bound   | 3.5589
+",
+        );
+
+    // means far apart leave no tail
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+a 10
+a 11
+a 12
+a 13
+a 10
+a 11
+a 12
+a 13
+b 20
+b 21
+b 22
+b 23
+b 20
+b 21
+b 22
+b 23
+c 30
+c 31
+c 32
+c 33
+c 30
+c 31
+c 32
+c 33",
+        )
+        .statc("anova data_set_1 -g -p tukey")
+        .expect_stdout(
+            "
+---
+H0: all population means are equal
+[a]
+sample size          | 8
+mean                 | 11.5
+sample variance      | 1.4285714286
+[b]
+sample size          | 8
+mean                 | 21.5
+sample variance      | 1.4285714286
+[c]
+sample size          | 8
+mean                 | 31.5
+sample variance      | 1.4285714286
+[between groups]
+sum of squares       | 1600
+degrees of freedom   | 2
+mean square          | 800
+[within groups]
+sum of squares       | 30
+degrees of freedom   | 21
+mean square          | 1.4285714286
+[total]
+sum of squares       | 1630
+degrees of freedom   | 23
+[F-test]
+F statistic          | 560
+right-tailed p-value | 0
+[Tukey HSD]
+[a - b]
+difference of means  | -10
+standard error       | 0.5976143047
+q statistic          | 23.6643191324
+adjusted p-value     | 3.78808096e-13
+95% CI lower bound   | -11.5063289077
+95% CI upper bound   | -8.4936710923
+[a - c]
+difference of means  | -20
+standard error       | 0.5976143047
+q statistic          | 47.3286382648
+adjusted p-value     | 0
+95% CI lower bound   | -21.5063289077
+95% CI upper bound   | -18.4936710923
+[b - c]
+difference of means  | -10
+standard error       | 0.5976143047
+q statistic          | 23.6643191324
+adjusted p-value     | 3.78808096e-13
+95% CI lower bound   | -11.5063289077
+95% CI upper bound   | -8.4936710923
+",
+        );
+
+    // their means are not exactly 0.1 and 0.7
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+0.1
+0.1
+0.1",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+0.7
+0.7
+0.7",
+        )
+        .statc("anova data_set_1 data_set_2")
+        .expect_stdout(
+            "
+---
+The samples have no variance within them.
+",
+        );
+
+    // probabilities are not observations
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.2
+20 0.3
+30 0.5",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+8
+12
+9",
+        )
+        .statc("anova weighted data_set_2")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+}
+
+#[test]
+fn regress_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+1 2.0
+2 4.1
+3 5.9
+4 8.2
+5 9.8",
+        )
+        .statc("regress data_set_1 6")
+        .expect_stdout(
+            "
+---
+y = a + bx
+sample size        | 5
+degrees of freedom | 3
+R²                 | 0.9976606684
+residual std.err   | 0.174164673
+[intercept a]
+estimate           | 0.09
+standard error     | 0.1826654501
+t statistic        | 0.4927040113
+two-tailed p-value | 0.6560281257
+95% CI lower bound | -0.4913229868
+95% CI upper bound | 0.6713229868
+[slope b]
+estimate           | 1.97
+standard error     | 0.0550757055
+t statistic        | 35.7689471807
+two-tailed p-value | 0.0000480542
+95% CI lower bound | 1.7947245246
+95% CI upper bound | 2.1452754754
+[x = 6]
+fitted value       | 11.91
+95% CI lower bound | 11.3286770132
+95% CI upper bound | 12.4913229868
+95% PI lower bound | 11.1067868665
+95% PI upper bound | 12.7132131335
+",
+        );
+}
+
+#[test]
+fn corr_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+1 2
+2 1
+3 4
+4 3
+5 5
+6 7
+7 6
+8 8",
+        )
+        .statc("corr data_set_1")
+        .expect_stdout(
+            "
+---
+sample size        | 8
+[Pearson]
+r                  | 0.9285714286
+t statistic        | 6.1282587703
+degrees of freedom | 6
+two-tailed p-value | 0.0008629682
+95% CI lower bound | 0.6477404043
+95% CI upper bound | 0.9872488112
+[Spearman]
+ρ                  | 0.9285714286
+t statistic        | 6.1282587703
+two-tailed p-value | 0.0008629682
+[Kendall]
+τ                  | 0.7857142857
+z statistic        | 2.7217941262
+two-tailed p-value | 0.0064928577
+",
+        );
+
+    // points on a line leave t infinite
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+1 2
+2 4
+3 6
+4 8
+5 10",
+        )
+        .statc("corr data_set_1")
+        .expect_stdout(
+            "
+---
+sample size        | 5
+[Pearson]
+r                  | 1
+degrees of freedom | 3
+95% CI lower bound | 1
+95% CI upper bound | 1
+[Spearman]
+ρ                  | 1
+[Kendall]
+τ                  | 1
+z statistic        | 2.4494897428
+two-tailed p-value | 0.0143058784
+",
+        );
+}
+
+#[test]
+fn eval_test() {
+    statc_test!().statc("eval 1+2+3+4").expect_stdout("10\n");
+    statc_test!().statc("eval 5/2").expect_stdout("2.5\n");
+}
+
+#[test]
+fn data_test() {
+    statc_test!()
+        .file_with_text(
+            "cool_filename",
+            "
+10
+20
+30
+50",
+        )
+        .statc("data cool_filename")
+        .expect_stdout(
+            "
+---
+mean                | 27.5
+population variance | 218.75
+population std.dev  | 14.7901994577
+sample variance     | 291.6666666667
+sample std.dev      | 17.0782512766
+std.err of mean     | 8.5391256383
+coeff. of variation | 0.6210273191
+minimum             | 10
+1st quartile        | 15
+median              | 25
+3rd quartile        | 40
+maximum             | 50
+range               | 40
+interquartile range | 25
+skewness            | 0.4346507596
+excess kurtosis     | -1.1542857143
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "even_cooler_name",
+            "
+10 0.1
+20 0.2
+30 0.4
+50 0.3",
+        )
+        .statc("data even_cooler_name")
+        .expect_stdout(
+            "
+---
+mean                | 32
+population variance | 176
+population std.dev  | 13.2664991614
+coeff. of variation | 0.4145780988
+minimum             | 10
+1st quartile        | 20
+median              | 30
+3rd quartile        | 50
+maximum             | 50
+range               | 40
+interquartile range | 30
+mode                | 30
+skewness            | 0.1439031417
+excess kurtosis     | -1.0929752066
+",
+        );
+}
+
+#[test]
+fn diff_test() {
+    statc_test!()
+        .file_with_text(
+            "cool_filename",
+            "
+10 11
+20 23
+30 35
+50 52",
+        )
+        .statc("diff cool_filename")
+        .expect_stdout(
+            "
+---
+mean                | -2.75
+population variance | 2.1875
+population std.dev  | 1.4790199458
+sample variance     | 2.9166666667
+sample std.dev      | 1.7078251277
+std.err of mean     | 0.8539125638
+coeff. of variation | 0.6210273191
+minimum             | -5
+1st quartile        | -4
+median              | -2.5
+3rd quartile        | -1.5
+maximum             | -1
+range               | 4
+interquartile range | 2.5
+skewness            | -0.4346507596
+excess kurtosis     | -1.1542857143
+",
+        );
+}
+
+#[test]
+fn comp_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+9
+1
+4
+5",
+        )
+        .statc("comp data_set_1 data_set_2")
+        .expect_stdout(
+            "
+---
+[data_set_1]
+mean                   | 27.5
+population variance    | 218.75
+population std.dev     | 14.7901994577
+sample variance        | 291.6666666667
+sample std.dev         | 17.0782512766
+std.err of mean        | 8.5391256383
+coeff. of variation    | 0.6210273191
+minimum                | 10
+1st quartile           | 15
+median                 | 25
+3rd quartile           | 40
+maximum                | 50
+range                  | 40
+interquartile range    | 25
+skewness               | 0.4346507596
+excess kurtosis        | -1.1542857143
+[data_set_2]
+mean                   | 4.75
+population variance    | 8.1875
+population std.dev     | 2.8613807856
+sample variance        | 10.9166666667
+sample std.dev         | 3.3040379336
+std.err of mean        | 1.6520189668
+coeff. of variation    | 0.6955869334
+minimum                | 1
+1st quartile           | 2.5
+median                 | 4.5
+3rd quartile           | 7
+maximum                | 9
+range                  | 8
+interquartile range    | 4.5
+skewness               | 0.2521069721
+excess kurtosis        | -1.0445778218
+[pooled sample]
+[1] sample size        | 4
+[1] sample variance    | 291.6666666667
+[2] sample size        | 4
+[2] sample variance    | 10.9166666667
+pooled sample variance | 151.2916666667
+pooled sample std.dev  | 12.3000677505
+",
+        );
+}
+
+#[test]
+fn box_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+12
+13
+15
+15
+16
+18
+19
+22
+48",
+        )
+        .statc("box data_set_1")
+        .expect_stdout(
+            "
+---
+minimum             | 10
+1st quartile        | 13
+median              | 15.5
+3rd quartile        | 19
+maximum             | 48
+interquartile range | 6
+lower fence         | 4
+upper fence         | 28
+outlier             | 48
+box plot            | ├───[══│════]──┤                                 ∘
+",
+        );
+}
+
+#[test]
+fn hist_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+12
+13
+15
+15
+16
+18
+19
+22
+48",
+        )
+        .statc("hist data_set_1")
+        .expect_stdout(
+            "
+---
+bins         | 5
+bin width    | 7.6
+[10, 17.6)   | ██████████████████████████████████████████████████ 6
+[17.6, 25.2) | █████████████████████████ 3
+[25.2, 32.8) | 0
+[32.8, 40.4) | 0
+[40.4, 48]   | ████████▍ 1
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+12
+13
+15
+15
+16
+18
+19
+22
+48",
+        )
+        .statc("hist data_set_1 --rule fd")
+        .expect_stdout(
+            "
+---
+bins         | 7
+bin width    | 5.6
+[10, 15.6)   | ██████████████████████████████████████████████████ 5
+[15.6, 21.2) | ██████████████████████████████ 3
+[21.2, 26.8) | ██████████ 1
+[26.8, 32.4) | 0
+[32.4, 38)   | 0
+[38, 43.6)   | 0
+[43.6, 48]   | ██████████ 1
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "even_cooler_name",
+            "
+10 0.1
+20 0.2
+30 0.4
+50 0.3",
+        )
+        .statc("hist even_cooler_name -w 10")
+        .expect_stdout(
+            "
+---
+bins      | 4
+bin width | 10
+[10, 20)  | ████████████▌ 0.1
+[20, 30)  | █████████████████████████ 0.2
+[30, 40)  | ██████████████████████████████████████████████████ 0.4
+[40, 50]  | █████████████████████████████████████▌ 0.3
+",
+        );
+}
+
+#[test]
+fn stem_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+12
+13
+15
+15
+16
+18
+19
+22
+48",
+        )
+        .statc("stem data_set_1")
+        .expect_stdout(
+            "
+---
+leaf unit | 1
+1         | 02355689
+2         | 2
+3         |
+4         | 8
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+-12
+-3
+0
+4
+15
+27",
+        )
+        .statc("stem data_set_1")
+        .expect_stdout(
+            "
+---
+leaf unit | 1
+-1        | 2
+-0        | 3
+0         | 04
+1         | 5
+2         | 7
+",
+        );
+}
+
+#[test]
+fn ttest_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .statc("ttest one data_set_1 20")
+        .expect_stdout(
+            "
+---
+H0: μ = 20
+sample size          | 4
+point estimate       | 27.5
+standard error       | 8.5391256383
+t statistic          | 0.8783100657
+degrees of freedom   | 3
+left-tailed p-value  | 0.7777809574
+right-tailed p-value | 0.2222190426
+two-tailed p-value   | 0.4444380851
+95% CI lower bound   | 0.324691162
+95% CI upper bound   | 54.675308838
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "same",
+            "
+5
+5
+5",
+        )
+        .statc("ttest one same 4")
+        .expect_stdout(
+            "
+---
+The data has no variance, so t is undefined.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "same",
+            "
+3.3
+3.3
+3.3
+3.3
+3.3
+3.3",
+        )
+        .statc("ttest one same 3")
+        .expect_stdout(
+            "
+---
+The data has no variance, so t is undefined.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ttest one weighted 20")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "pairs",
+            "
+10 11
+20 23
+30 35
+50 52",
+        )
+        .statc("ttest paired pairs")
+        .expect_stdout(
+            "
+---
+H0: μd = 0
+sample size          | 4
+point estimate       | -2.75
+standard error       | 0.8539125638
+t statistic          | -3.2204702407
+degrees of freedom   | 3
+left-tailed p-value  | 0.0242834283
+right-tailed p-value | 0.9757165717
+two-tailed p-value   | 0.0485668566
+95% CI lower bound   | -5.4675308838
+95% CI upper bound   | -0.0324691162
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+9
+1
+4
+5",
+        )
+        .statc("ttest welch data_set_1 data_set_2 -a 0.1")
+        .expect_stdout(
+            "
+---
+H0: μ1 - μ2 = 0 (Welch)
+[1] sample size      | 4
+[2] sample size      | 4
+point estimate       | 22.75
+standard error       | 8.6974613154
+t statistic          | 2.6157057991
+degrees of freedom   | 3.224257267
+left-tailed p-value  | 0.9631948277
+right-tailed p-value | 0.0368051723
+two-tailed p-value   | 0.0736103446
+90% CI lower bound   | 2.8492545942
+90% CI upper bound   | 42.6507454058
+",
+        );
+}
+
+#[test]
+fn ztest_test() {
+    statc_test!().statc("ztest one 10.5 2 16 10").expect_stdout(
+        "
+---
+H0: μ = 10
+sample size        | 16
+point estimate     | 10.5
+standard error     | 0.5
+z statistic        | 1
+two-tailed p-value | 0.3173105079
+reject H0 if |z| > | 1.9599639845
+[do not reject H0 at 5% significance]
+95% CI lower bound | 9.5200180077
+95% CI upper bound | 11.4799819923
+",
+    );
+
+    statc_test!().statc("ztest two 5 3 9 4 4 16 -t left").expect_stdout(
+        "
+---
+H0: μ1 - μ2 = 0
+[1] sample size     | 9
+[2] sample size     | 16
+point estimate      | 1
+standard error      | 1.4142135624
+z statistic         | 0.7071067812
+left-tailed p-value | 0.7602499389
+reject H0 if z <    | -1.644853627
+[do not reject H0 at 5% significance]
+95% CI lower bound  | -1.7718076487
+95% CI upper bound  | 3.7718076487
+",
+    );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+9
+1
+4
+5",
+        )
+        .statc("ztest two-file data_set_1 15 data_set_2 3 -t right")
+        .expect_stdout(
+            "
+---
+H0: μ1 - μ2 = 0
+[1] sample size      | 4
+[2] sample size      | 4
+point estimate       | 22.75
+standard error       | 7.6485292704
+z statistic          | 2.9744280496
+right-tailed p-value | 0.0014676764
+reject H0 if z >     | 1.644853627
+[reject H0 at 5% significance]
+95% CI lower bound   | 7.7591580953
+95% CI upper bound   | 37.7408419047
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ztest one-file weighted 2 10")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ztest two-file data_set_1 15 weighted 3")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+}
+
+#[test]
+fn ci_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .statc("ci mean data_set_1")
+        .expect_stdout(
+            "
+---
+CI for μ (σ unknown)
+sample size        | 4
+point estimate     | 27.5
+standard error     | 8.5391256383
+degrees of freedom | 3
+95% CI lower bound | 0.324691162
+95% CI upper bound | 54.675308838
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .statc("ci mean data_set_1 --sigma 15")
+        .expect_stdout(
+            "
+---
+CI for μ (σ known)
+sample size        | 4
+point estimate     | 27.5
+standard error     | 7.5
+95% CI lower bound | 12.8002701159
+95% CI upper bound | 42.1997298841
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+9
+1
+4
+5",
+        )
+        .statc("ci diff data_set_1 data_set_2")
+        .expect_stdout(
+            "
+---
+CI for μ1 - μ2 (pooled)
+[1] sample size    | 4
+[2] sample size    | 4
+point estimate     | 22.75
+standard error     | 8.6974613154
+degrees of freedom | 6
+95% CI lower bound | 1.4680788324
+95% CI upper bound | 44.0319211676
+",
+        );
+
+    statc_test!().statc("ci prop 40 100 -a 0.05").expect_stdout(
+        "
+---
+CI for p
+sample size        | 100
+point estimate     | 0.4
+standard error     | 0.0489897949
+95% CI lower bound | 0.3039817665
+95% CI upper bound | 0.4960182335
+",
+    );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .statc("ci var data_set_1")
+        .expect_stdout(
+            "
+---
+CI for σ²
+sample size        | 4
+sample variance    | 291.6666666667
+degrees of freedom | 3
+95% CI lower bound | 93.5988685361
+95% CI upper bound | 4054.7688965241
+[95% CI for σ]
+95% CI lower bound | 9.6746508224
+95% CI upper bound | 63.6770672733
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ci mean weighted")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ci mean weighted --sigma 15")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ci var weighted")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "same",
+            "
+3.3
+3.3
+3.3",
+        )
+        .statc("ci var same")
         .expect_stdout(
             "
 ---
-mean                | 27.5
-population variance | 218.75
-population std.dev  | 14.7901994577
-sample variance     | 291.6666666667
-sample std.err      | 17.0782512766
+The data has no variance.
 ",
         );
 
     statc_test!()
         .file_with_text(
-            "even_cooler_name",
+            "data_set_1",
             "
-10 0.1
-20 0.2
-30 0.4
-50 0.3",
+10
+20
+30
+50",
         )
-        .statc("data even_cooler_name")
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ci diff data_set_1 weighted")
         .expect_stdout(
             "
 ---
-mean                | 32
-population variance | 176
-population std.dev  | 13.2664991614
-sample variance     | 234.6666666667
-sample std.err      | 15.3188337241
+samples need one number per line, not probabilities.
 ",
         );
 }
 
 #[test]
-fn diff_test() {
+fn prop_test() {
+    statc_test!().statc("prop one 7 20").expect_stdout(
+        "
+---
+H0: p = 0.5
+sample size          | 20
+point estimate       | 0.35
+[exact binomial]
+left-tailed p-value  | 0.1315879822
+right-tailed p-value | 0.9423408508
+two-tailed p-value   | 0.2631759644
+[normal approximation]
+standard error       | 0.1118033989
+z statistic          | -1.1180339887
+left-tailed p-value  | 0.1317762386
+right-tailed p-value | 0.9412375659
+two-tailed p-value   | 0.2635524773
+",
+    );
+
+    // no count is more likely than x = np, so every count is summed
+    statc_test!().statc("prop one 50000000 100000000").expect_stdout(
+        "
+---
+H0: p = 0.5
+sample size          | 100000000
+point estimate       | 0.5
+[exact binomial]
+left-tailed p-value  | 0.5000398942
+right-tailed p-value | 0.5000398942
+two-tailed p-value   | 1
+[normal approximation]
+standard error       | 0.00005
+z statistic          | 0
+left-tailed p-value  | 0.5000398942
+right-tailed p-value | 0.5000398942
+two-tailed p-value   | 1
+",
+    );
+
+    statc_test!().statc("prop two 45 100 30 100").expect_stdout(
+        "
+---
+H0: p1 = p2
+[1] sample size      | 100
+[2] sample size      | 100
+[1] point estimate   | 0.45
+[2] point estimate   | 0.3
+pooled proportion    | 0.375
+standard error       | 0.0684653197
+z statistic          | 2.19089023
+left-tailed p-value  | 0.9857701315
+right-tailed p-value | 0.0142298685
+two-tailed p-value   | 0.0284597369
+[p1 - p2]
+point estimate       | 0.15
+standard error       | 0.0676387463
+95% CI lower bound   | 0.0174304933
+95% CI upper bound   | 0.2825695067
+",
+    );
+
+    statc_test!().statc("prop ci 40 100").expect_stdout(
+        "
+---
+CI for p
+sample size        | 100
+point estimate     | 0.4
+standard error     | 0.0489897949
+[Wald]
+95% CI lower bound | 0.3039817665
+95% CI upper bound | 0.4960182335
+[Wilson]
+95% CI lower bound | 0.3094012864
+95% CI upper bound | 0.4979974132
+[Clopper-Pearson]
+95% CI lower bound | 0.3032947687
+95% CI upper bound | 0.5027908496
+",
+    );
+}
+
+#[test]
+fn sign_test() {
     statc_test!()
         .file_with_text(
-            "cool_filename",
+            "data_set_1",
             "
-10 11
-20 23
-30 35
-50 52",
+1.83 0.878
+0.5 0.647
+1.62 0.598
+2.48 2.05
+1.68 1.06
+1.88 1.29
+1.55 1.06
+3.06 3.15
+1.3 1.29",
         )
-        .statc("diff cool_filename")
+        .statc("sign data_set_1 --paired")
         .expect_stdout(
             "
 ---
-mean                | -2.75
-population variance | 2.1875
-population std.dev  | 1.4790199458
-sample variance     | 2.9166666667
-sample std.err      | 1.7078251277
+H0: median = 0
+sample size          | 9
+values above median  | 7
+values below median  | 2
+[exact p-values]
+left-tailed p-value  | 0.98046875
+right-tailed p-value | 0.08984375
+two-tailed p-value   | 0.1796875
+",
+        );
+
+    // a <value> <probability> file is not 2n observations
+    statc_test!()
+        .file_with_text(
+            "weighted",
+            "
+10 0.2
+20 0.3
+30 0.5",
+        )
+        .statc("sign weighted 15")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
 ",
         );
 }
 
 #[test]
-fn comp_test() {
+fn wilcoxon_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+1.83 0.878
+0.5 0.647
+1.62 0.598
+2.48 2.05
+1.68 1.06
+1.88 1.29
+1.55 1.06
+3.06 3.15
+1.3 1.29",
+        )
+        .statc("wilcoxon data_set_1 --paired")
+        .expect_stdout(
+            "
+---
+H0: symmetric about median = 0
+sample size          | 9
+W+ statistic         | 40
+W- statistic         | 5
+[exact p-values]
+left-tailed p-value  | 0.986328125
+right-tailed p-value | 0.01953125
+two-tailed p-value   | 0.0390625
+",
+        );
+
+    // ties in |x - median| use the normal approximation
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+5
+7
+8
+3
+9
+12
+6
+4",
+        )
+        .statc("wilcoxon data_set_1 5")
+        .expect_stdout(
+            "
+---
+H0: symmetric about median = 5
+sample size          | 7
+W+ statistic         | 23
+W- statistic         | 5
+[normal approximation]
+z statistic          | 1.5267400734
+left-tailed p-value  | 0.9365871708
+right-tailed p-value | 0.0634128292
+two-tailed p-value   | 0.1268256584
+",
+        );
+}
+
+#[test]
+fn mann_whitney_test() {
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+1
+2
+3
+4
+5",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+3.5
+6
+7
+8
+9
+10",
+        )
+        .statc("mann-whitney data_set_1 data_set_2")
+        .expect_stdout(
+            "
+---
+H0: both samples come from the same distribution
+[1] sample size      | 5
+[2] sample size      | 6
+[1] rank sum         | 17
+U statistic          | 2
+[exact p-values]
+left-tailed p-value  | 0.0086580087
+right-tailed p-value | 0.9956709957
+two-tailed p-value   | 0.0173160173
+",
+        );
+}
+
+#[test]
+fn gof_test() {
+    statc_test!()
+        .file_with_text(
+            "counts",
+            "
+10 0.2
+20 0.3
+30 0.5",
+        )
+        .statc("gof counts")
+        .expect_stdout(
+            "
+---
+H0: data follows the expected counts
+expected @ row 1   | 12
+expected @ row 2   | 18
+expected @ row 3   | 30
+χ² statistic       | 0.5555555556
+degrees of freedom | 2
+p-value            | 0.7574651284
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "counts",
+            "
+0 12
+1 20
+2 8
+3 3",
+        )
+        .statc("gof counts --fit pois")
+        .expect_stdout(
+            "
+---
+H0: X ~ Poisson(1.0465116279)
+expected @ 0       | 15.0999055343
+expected @ 1       | 15.8022267218
+expected @ 2       | 8.2686070055
+expected @ 3+      | 3.8292607384
+χ² statistic       | 1.9398136512
+degrees of freedom | 2
+p-value            | 0.3791183606
+[warning: 1 expected count(s) below 5, the χ² approximation may be poor]
+",
+        );
+}
+
+#[test]
+fn chisq_indep_test() {
+    statc_test!()
+        .file_with_text(
+            "table",
+            "
+10 20 30
+15 25 20",
+        )
+        .statc("chisq-indep table")
+        .expect_stdout(
+            "
+---
+H0: rows and columns are independent
+expected @ (1, 1)  | 12.5
+expected @ (1, 2)  | 22.5
+expected @ (1, 3)  | 25
+expected @ (2, 1)  | 12.5
+expected @ (2, 2)  | 22.5
+expected @ (2, 3)  | 25
+χ² statistic       | 3.5555555556
+degrees of freedom | 2
+p-value            | 0.1690133154
+",
+        );
+}
+
+#[test]
+fn ftest_test() {
     statc_test!()
         .file_with_text(
             "data_set_1",
@@ -570,29 +2921,202 @@ fn comp_test() {
 4
 5",
         )
-        .statc("comp data_set_1 data_set_2")
+        .statc("ftest data_set_1 data_set_2")
         .expect_stdout(
             "
 ---
-[data_set_1]
-mean                   | 27.5
-population variance    | 218.75
-population std.dev     | 14.7901994577
-sample variance        | 291.6666666667
-sample std.err         | 17.0782512766
-[data_set_2]
-mean                   | 4.75
-population variance    | 8.1875
-population std.dev     | 2.8613807856
-sample variance        | 10.9166666667
-sample std.err         | 3.3040379336
-[pooled sample]
-[1] sample size        | 4
-[1] sample variance    | 291.6666666667
-[2] sample size        | 4
-[2] sample variance    | 10.9166666667
-pooled sample variance | 151.2916666667
-pooled sample std.dev  | 12.3000677505
+H0: σ1² = σ2²
+[1] sample size      | 4
+[1] sample variance  | 291.6666666667
+[2] sample size      | 4
+[2] sample variance  | 10.9166666667
+F statistic          | 26.7175572519
+degrees of freedom 1 | 3
+degrees of freedom 2 | 3
+left-tailed p-value  | 0.9884930778
+right-tailed p-value | 0.0115069222
+two-tailed p-value   | 0.0230138444
+[95% CI for σ1²/σ2²]
+95% CI lower bound   | 1.730491844
+95% CI upper bound   | 412.3791408528
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "same",
+            "
+5
+5
+5",
+        )
+        .statc("ftest same same")
+        .expect_stdout(
+            "
+---
+The second sample has no variance.
+",
+        );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20
+30
+50",
+        )
+        .file_with_text(
+            "weighted",
+            "
+10 0.5
+20 0.5",
+        )
+        .statc("ftest data_set_1 weighted")
+        .expect_stdout(
+            "
+---
+samples need one number per line, not probabilities.
+",
+        );
+}
+
+#[test]
+fn json_format_test() {
+    statc_test!().statc("binom 10 0.2 4 --format json").expect_stdout(
+        r#"{"title":"X ~ B(10, 0.2)","lines":[{"desc":"expected","value":2},{"desc":"variance","value":1.6000000000000003},{"desc":"P(X = 4)","value":0.08808038399999976},{"desc":"P(X <= 4)","value":0.9672065024000001},{"desc":"P(X > 4)","value":0.03279349759999989}]}
+"#,
+    );
+
+    statc_test!().statc("--format json vpool 3 2.3 4 5.4").expect_stdout(
+        r#"{"title":"","lines":[{"desc":"[1] sample size","value":3},{"desc":"[1] sample variance","value":2.3},{"desc":"[2] sample size","value":4},{"desc":"[2] sample variance","value":5.4},{"desc":"pooled sample variance","value":4.160000000000001},{"desc":"pooled sample std.dev","value":2.039607805437114}]}
+"#,
+    );
+
+    statc_test!()
+        .file_with_text(
+            "data_set_1",
+            "
+10
+20",
+        )
+        .file_with_text(
+            "data_set_2",
+            "
+9
+1",
+        )
+        .statc("comp data_set_1 data_set_2 --format json")
+        .expect_stdout(
+            r#"{"title":"","lines":[{"header":"data_set_1"},{"desc":"mean","value":15},{"desc":"population variance","value":25},{"desc":"population std.dev","value":5},{"desc":"sample variance","value":50},{"desc":"sample std.dev","value":7.0710678118654755},{"desc":"std.err of mean","value":5},{"desc":"coeff. of variation","value":0.4714045207910317},{"desc":"minimum","value":10},{"desc":"1st quartile","value":10},{"desc":"median","value":15},{"desc":"3rd quartile","value":20},{"desc":"maximum","value":20},{"desc":"range","value":10},{"desc":"interquartile range","value":10},{"desc":"skewness","value":0},{"desc":"excess kurtosis","value":-2},{"header":"data_set_2"},{"desc":"mean","value":5},{"desc":"population variance","value":16},{"desc":"population std.dev","value":4},{"desc":"sample variance","value":32},{"desc":"sample std.dev","value":5.656854249492381},{"desc":"std.err of mean","value":4},{"desc":"coeff. of variation","value":1.131370849898476},{"desc":"minimum","value":1},{"desc":"1st quartile","value":1},{"desc":"median","value":5},{"desc":"3rd quartile","value":9},{"desc":"maximum","value":9},{"desc":"range","value":8},{"desc":"interquartile range","value":8},{"desc":"skewness","value":0},{"desc":"excess kurtosis","value":-2},{"header":"pooled sample"},{"desc":"[1] sample size","value":2},{"desc":"[1] sample variance","value":50},{"desc":"[2] sample size","value":2},{"desc":"[2] sample variance","value":32},{"desc":"pooled sample variance","value":41},{"desc":"pooled sample std.dev","value":6.4031242374328485}]}
+"#,
+        );
+
+    statc_test!()
+        .statc("eval foo --format json")
+        .expect_stdout("{\"error\":\"Invalid expression.\"}\n");
+}
+
+#[test]
+fn table_format_test() {
+    statc_test!().statc("binom 10 0.2 4 --format csv").expect_stdout(
+        r#"
+---
+"X ~ B(10, 0.2)",value
+expected,2
+variance,1.6
+P(X = 4),0.088080384
+P(X <= 4),0.9672065024
+P(X > 4),0.0327934976
+"#,
+    );
+
+    statc_test!().statc("vpool 3 2.3 4 5.4 --format tsv").expect_stdout(
+        "
+---
+description\tvalue
+[1] sample size\t3
+[1] sample variance\t2.3
+[2] sample size\t4
+[2] sample variance\t5.4
+pooled sample variance\t4.16
+pooled sample std.dev\t2.0396078054
+",
+    );
+
+    statc_test!().statc("ztest one 10.5 2 16 10 --format markdown").expect_stdout(
+        r#"
+---
+| H0: μ = 10 | value |
+| --- | ---: |
+| sample size | 16 |
+| point estimate | 10.5 |
+| standard error | 0.5 |
+| z statistic | 1 |
+| two-tailed p-value | 0.3173105079 |
+| reject H0 if \|z\| > | 1.9599639845 |
+| **do not reject H0 at 5% significance** | |
+| 95% CI lower bound | 9.5200180077 |
+| 95% CI upper bound | 11.4799819923 |
+"#,
+    );
+
+    statc_test!().statc("inorm 0 1 mid 0.95 --format latex").expect_stdout(
+        r#"
+---
+\begin{tabular}{l r}
+\hline
+X \textasciitilde{} N(0, 1²) & value \\
+\hline
+a: left bound & -1.9599639845 \\
+b: right bound & 1.9599639845 \\
+P(a < X < b) & 0.95 \\
+\hline
+\end{tabular}
+"#,
+    );
+}
+
+#[test]
+fn precision_test() {
+    statc_test!().statc("norm 0 1 1 --precision 4").expect_stdout(
+        "
+---
+X ~ N(0, 1²)
+expected  | 0
+variance  | 1
+pdf @ 1   | 0.242
+P(X <= 1) | 0.8413
+P(X > 1)  | 0.1587
+",
+    );
+
+    statc_test!().statc("binom 100 0.5 5 --sig-figs 3").expect_stdout(
+        "
+---
+X ~ B(100, 0.5)
+expected  | 50
+variance  | 25
+P(X = 5)  | 5.94e-23
+P(X <= 5) | 6.26e-23
+P(X > 5)  | 1
+",
+    );
+
+    statc_test!()
+        .statc("--format csv --precision 2 pois 3 2")
+        .expect_stdout(
+            "
+---
+X ~ Poisson(3),value
+expected,3
+variance,3
+P(X = 2),0.22
+P(X <= 2),0.42
+P(X > 2),0.58
 ",
         );
+
+    statc_test!().statc("eval 1e20").expect_stdout("1e20\n");
 }
@@ -1,7 +1,7 @@
 #[macro_export]
 macro_rules! statc_test {
     () => {{
-        use crate::test::test;
+        use $crate::test::test;
         fn f() {}
         fn type_name_of<'a, T>(_: T) -> &'a str {
             std::any::type_name::<T>()
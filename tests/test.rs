@@ -76,7 +76,7 @@ impl Test {
     #[allow(unused)]
     pub fn shell(&mut self, args: &str) -> &mut Self {
         let args: Vec<&str> = args.split(' ').collect();
-        if args.len() == 0 {
+        if args.is_empty() {
             return self;
         }
         self.received = Command::new(args[0])
@@ -27,8 +27,10 @@ impl ShellString for Command {
     }
 }
 
+#[allow(unused)]
 pub type Result<T> = std::result::Result<T, String>;
 
+#[allow(unused)]
 pub trait StringError<T, E> {
     fn serr(self, err_msg: &str) -> Result<T>;
     fn clear(self) -> std::result::Result<(), E>;
@@ -39,6 +41,6 @@ impl<T, E> StringError<T, E> for std::result::Result<T, E> {
         self.map_err(|_| err_msg.to_string())
     }
     fn clear(self) -> std::result::Result<(), E> {
-        return self.map(|_| ());
+        self.map(|_| ())
     }
 }